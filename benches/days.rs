@@ -0,0 +1,44 @@
+use advent_2022::days::*;
+use advent_2022::input::{self, Source};
+use advent_2022::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parsing and both parts of a day on its real input, in a group
+/// named `dayN` so that `cargo bench -- dayN/` runs only that day.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, sample_size: usize) {
+    let input = input::read(day, &Source::Default).unwrap();
+    let parsed = S::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{day}"));
+    group.sample_size(sample_size);
+    group.bench_function("gen", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("p1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("p2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn fast(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, 1, 100);
+    bench_day::<day2::Day2>(c, 2, 100);
+    bench_day::<day3::Day3>(c, 3, 100);
+    bench_day::<day4::Day4>(c, 4, 100);
+    bench_day::<day5::Day5>(c, 5, 100);
+    bench_day::<day6::Day6>(c, 6, 100);
+    bench_day::<day7::Day7>(c, 7, 100);
+    bench_day::<day8::Day8>(c, 8, 100);
+    bench_day::<day9::Day9>(c, 9, 100);
+    bench_day::<day10::Day10>(c, 10, 100);
+    bench_day::<day11::Day11>(c, 11, 100);
+    bench_day::<day12::Day12>(c, 12, 100);
+    bench_day::<day13::Day13>(c, 13, 100);
+    bench_day::<day14::Day14>(c, 14, 100);
+}
+
+fn slow(c: &mut Criterion) {
+    bench_day::<day15::Day15>(c, 15, 10);
+    bench_day::<day16::Day16>(c, 16, 10);
+    bench_day::<day19::Day19>(c, 19, 10);
+}
+
+criterion_group!(benches, fast, slow);
+criterion_main!(benches);
@@ -0,0 +1,700 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// SplitMix64, so that a seed gives the same input on every platform and
+/// whatever the version of our dependencies.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `lo..=hi`
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {lo}..={hi}");
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i64
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0, n as i64 - 1) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generates a valid input for `day`. What `size` counts depends on the day:
+///
+/// | day | size |
+/// |-----|------|
+/// | 1   | elves |
+/// | 2, 4, 9, 10 | lines |
+/// | 3   | groups of three rucksacks |
+/// | 5   | moves (the stacks grow with it) |
+/// | 6   | characters in the datastream |
+/// | 7   | directories |
+/// | 8   | side of the square forest |
+/// | 11  | items held by the eight monkeys |
+/// | 12  | width of the heightmap |
+/// | 13  | pairs of packets |
+/// | 14  | rock paths (the cave gets deeper with it) |
+/// | 15  | sensors, at least 4 |
+/// | 16  | valves, at most 676 |
+/// | 19  | blueprints, between 3 and 255 |
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<String> {
+    if size == 0 {
+        return Err(anyhow!("Size must be at least 1"));
+    }
+    let mut rng = Rng::new(seed);
+    let rng = &mut rng;
+    let input = match day {
+        1 => day1(rng, size),
+        2 => day2(rng, size),
+        3 => day3(rng, size),
+        4 => day4(rng, size),
+        5 => day5(rng, size),
+        6 => day6(rng, size),
+        7 => day7(rng, size),
+        8 => day8(rng, size),
+        9 => day9(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 if size >= 4 => day15(rng, size),
+        16 if size <= 676 => day16(rng, size),
+        19 if (3..=255).contains(&size) => day19(rng, size),
+        15 | 16 | 19 => return Err(anyhow!("Size {size} is out of range for day {day}")),
+        _ => return Err(anyhow!("No input generator for day {day}")),
+    };
+    Ok(input)
+}
+
+fn day1(rng: &mut Rng, elves: usize) -> String {
+    (0..elves)
+        .map(|_| {
+            let items = rng.range(1, 15);
+            (0..items).map(|_| rng.range(1000, 60000)).join("\n") + "\n"
+        })
+        .join("\n")
+}
+
+fn day2(rng: &mut Rng, lines: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..lines {
+        let o = rng.pick(&['A', 'B', 'C']);
+        let y = rng.pick(&['X', 'Y', 'Z']);
+        writeln!(s, "{o} {y}").unwrap();
+    }
+    s
+}
+
+/// Every group has a single badge, and every rucksack a single item in both
+/// compartments.
+fn day3(rng: &mut Rng, groups: usize) -> String {
+    let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut s = String::new();
+    for _ in 0..groups {
+        rng.shuffle(&mut letters);
+        let badge = letters[0];
+        for own in letters[1..].chunks(17) {
+            let (common, left, right) = (own[0], &own[1..9], &own[9..17]);
+            let badge_left = rng.chance(50);
+            let len = rng.range(2, 16) as usize;
+            let mut half = |pool: &[char], with_badge: bool| {
+                let mut half = vec![common];
+                if with_badge {
+                    half.push(badge);
+                }
+                while half.len() < len {
+                    half.push(*rng.pick(pool));
+                }
+                rng.shuffle(&mut half);
+                half
+            };
+            let first = half(left, badge_left);
+            let second = half(right, !badge_left);
+            let line: String = first.into_iter().chain(second).collect();
+            writeln!(s, "{line}").unwrap();
+        }
+    }
+    s
+}
+
+fn day4(rng: &mut Rng, lines: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..lines {
+        let a = rng.range(1, 99);
+        let b = rng.range(a, 99);
+        let c = rng.range(1, 99);
+        let d = rng.range(c, 99);
+        writeln!(s, "{a}-{b},{c}-{d}").unwrap();
+    }
+    s
+}
+
+/// Nine stacks, and no move ever empties a stack so both parts can read the top
+/// of every stack at the end.
+fn day5(rng: &mut Rng, moves: usize) -> String {
+    let max_height = (moves as i64 / 10).max(8);
+    let mut heights: Vec<usize> = (0..9).map(|_| rng.range(2, max_height) as usize).collect();
+    let tallest = *heights.iter().max().unwrap();
+
+    let mut s = String::new();
+    for level in (0..tallest).rev() {
+        let row = heights
+            .iter()
+            .map(|&h| {
+                if h > level {
+                    format!("[{}]", (b'A' + rng.below(26) as u8) as char)
+                } else {
+                    String::from("   ")
+                }
+            })
+            .join(" ");
+        writeln!(s, "{}", row.trim_end()).unwrap();
+    }
+    writeln!(s, "{}", (1..=9).map(|i| format!(" {i} ")).join(" ")).unwrap();
+    writeln!(s).unwrap();
+
+    for _ in 0..moves {
+        let from = loop {
+            let from = rng.below(9);
+            if heights[from] >= 2 {
+                break from;
+            }
+        };
+        let to = (from + 1 + rng.below(8)) % 9;
+        let num = rng.range(1, heights[from] as i64 - 1) as usize;
+        heights[from] -= num;
+        heights[to] += num;
+        writeln!(s, "move {num} from {} to {}", from + 1, to + 1).unwrap();
+    }
+    s
+}
+
+/// The stream only uses thirteen letters until the fourteen distinct ones at the
+/// very end. The character just before them repeats the first of them, so no
+/// earlier window can be a marker either.
+fn day6(rng: &mut Rng, len: usize) -> String {
+    let len = len.max(20);
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    letters.swap(0, rng.below(13));
+    rng.shuffle(&mut letters[1..]);
+    let marker = &letters[..14];
+
+    let mut s: String = (0..len - 15)
+        .map(|_| (b'a' + rng.below(13) as u8) as char)
+        .collect();
+    s.push(marker[0]);
+    s.extend(marker);
+    s.push('\n');
+    s
+}
+
+fn name(rng: &mut Rng) -> String {
+    let len = rng.range(1, 8);
+    (0..len)
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect()
+}
+
+/// Directories hang off one of the last few created ones, which makes for deep
+/// trees and long runs of `$ cd ..`.
+fn day7(rng: &mut Rng, dirs: usize) -> String {
+    struct Dir {
+        name: String,
+        names: BTreeSet<String>,
+        children: Vec<usize>,
+        files: Vec<(String, u64)>,
+    }
+    let new_dir = |name: String| Dir {
+        name,
+        names: BTreeSet::new(),
+        children: Vec::new(),
+        files: Vec::new(),
+    };
+    let fresh_name = |rng: &mut Rng, dir: &mut Dir, ext: bool| loop {
+        let mut n = name(rng);
+        if ext && rng.chance(50) {
+            n = format!("{n}.{}", rng.pick(&["txt", "dat", "log", "lst", "ext"]));
+        }
+        if dir.names.insert(n.clone()) {
+            break n;
+        }
+    };
+
+    let mut tree = vec![new_dir(String::from("/"))];
+    for i in 1..dirs {
+        let parent = i - 1 - rng.below(i.min(3));
+        let n = fresh_name(rng, &mut tree[parent], false);
+        tree[parent].children.push(i);
+        tree.push(new_dir(n));
+    }
+    for dir in tree.iter_mut() {
+        for _ in 0..rng.range(0, 4) {
+            let n = fresh_name(rng, dir, true);
+            dir.files.push((n, rng.range(1, 300_000) as u64));
+        }
+    }
+
+    enum Step {
+        Enter(usize),
+        Leave,
+    }
+    let mut s = String::new();
+    let mut stack = vec![Step::Enter(0)];
+    while let Some(step) = stack.pop() {
+        let i = match step {
+            Step::Enter(i) => i,
+            Step::Leave => {
+                s.push_str("$ cd ..\n");
+                continue;
+            }
+        };
+        let dir = &tree[i];
+        writeln!(s, "$ cd {}\n$ ls", dir.name).unwrap();
+        for &child in &dir.children {
+            writeln!(s, "dir {}", tree[child].name).unwrap();
+        }
+        for (n, size) in &dir.files {
+            writeln!(s, "{size} {n}").unwrap();
+        }
+        for &child in dir.children.iter().rev() {
+            stack.push(Step::Leave);
+            stack.push(Step::Enter(child));
+        }
+    }
+    s
+}
+
+fn day8(rng: &mut Rng, side: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..side {
+        let row: String = (0..side)
+            .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+            .collect();
+        writeln!(s, "{row}").unwrap();
+    }
+    s
+}
+
+fn day9(rng: &mut Rng, lines: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..lines {
+        let dir = rng.pick(&['R', 'U', 'L', 'D']);
+        writeln!(s, "{dir} {}", rng.range(1, 20)).unwrap();
+    }
+    s
+}
+
+/// `x` stays within -3..=3 so that the signal strengths of long programs do
+/// not overflow.
+fn day10(rng: &mut Rng, lines: usize) -> String {
+    let mut s = String::new();
+    let mut x = 1;
+    for _ in 0..lines {
+        if rng.chance(30) {
+            s.push_str("noop\n");
+        } else {
+            let target = rng.range(-3, 3);
+            writeln!(s, "addx {}", target - x).unwrap();
+            x = target;
+        }
+    }
+    s
+}
+
+#[derive(Clone, Copy)]
+enum MonkeyOp {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+/// Eight monkeys, each testing a different prime, so that part 2's modulus
+/// stays small. The operations are rerolled until twenty rounds of part 1 fit in
+/// a `u64`.
+fn day11(rng: &mut Rng, items: usize) -> String {
+    const MONKEYS: usize = 8;
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19];
+    rng.shuffle(&mut primes);
+    let throws: Vec<(usize, usize)> = (0..MONKEYS)
+        .map(|i| {
+            let t = (i + 1 + rng.below(MONKEYS - 1)) % MONKEYS;
+            let f = loop {
+                let f = (i + 1 + rng.below(MONKEYS - 1)) % MONKEYS;
+                if f != t {
+                    break f;
+                }
+            };
+            (t, f)
+        })
+        .collect();
+    let mut held = vec![Vec::new(); MONKEYS];
+    for _ in 0..items {
+        held[rng.below(MONKEYS)].push(rng.range(50, 99) as u64);
+    }
+
+    let ops = loop {
+        let mut ops: Vec<MonkeyOp> = (0..MONKEYS)
+            .map(|_| {
+                if rng.chance(50) {
+                    MonkeyOp::Add(rng.range(1, 8) as u64)
+                } else {
+                    MonkeyOp::Mul(rng.range(2, 19) as u64)
+                }
+            })
+            .collect();
+        ops[rng.below(MONKEYS)] = MonkeyOp::Square;
+        if day11_fits(&ops, &primes, &throws, &held) {
+            break ops;
+        }
+    };
+
+    let mut monkeys = Vec::new();
+    for i in 0..MONKEYS {
+        let op = match ops[i] {
+            MonkeyOp::Add(v) => format!("old + {v}"),
+            MonkeyOp::Mul(v) => format!("old * {v}"),
+            MonkeyOp::Square => String::from("old * old"),
+        };
+        monkeys.push(format!(
+            "Monkey {i}:
+  Starting items: {}
+  Operation: new = {op}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+            held[i].iter().join(", "),
+            primes[i],
+            throws[i].0,
+            throws[i].1
+        ));
+    }
+    monkeys.join("\n")
+}
+
+fn day11_fits(
+    ops: &[MonkeyOp],
+    primes: &[u64],
+    throws: &[(usize, usize)],
+    held: &[Vec<u64>],
+) -> bool {
+    let mut held = held.to_vec();
+    for _ in 0..20 {
+        for i in 0..held.len() {
+            for item in std::mem::take(&mut held[i]) {
+                let worry = match ops[i] {
+                    MonkeyOp::Add(v) => item.checked_add(v),
+                    MonkeyOp::Mul(v) => item.checked_mul(v),
+                    MonkeyOp::Square => item.checked_mul(item),
+                };
+                let Some(worry) = worry.map(|w| w / 3) else {
+                    return false;
+                };
+                let to = if worry % primes[i] == 0 {
+                    throws[i].0
+                } else {
+                    throws[i].1
+                };
+                held[to].push(worry);
+            }
+        }
+    }
+    true
+}
+
+/// A climbable path from `S` on the left edge to `E` on the right edge is laid
+/// over random terrain.
+fn day12(rng: &mut Rng, width: usize) -> String {
+    let width = width.max(32);
+    let height = (width / 4).max(5);
+    let mut grid: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| b'a' + rng.below(26) as u8).collect())
+        .collect();
+
+    let (mut x, mut y) = (0, rng.below(height));
+    let mut path = vec![(x, y)];
+    while x < width - 1 {
+        let up = rng.chance(50);
+        for _ in 0..rng.range(0, 3) {
+            match up {
+                true if y > 0 => y -= 1,
+                false if y < height - 1 => y += 1,
+                _ => break,
+            }
+            path.push((x, y));
+        }
+        x += 1;
+        path.push((x, y));
+    }
+    let last = path.len() - 1;
+    for (i, &(x, y)) in path.iter().enumerate() {
+        grid[y][x] = b'a' + (25 * i / last) as u8;
+    }
+    let (sx, sy) = path[0];
+    let (ex, ey) = path[last];
+    grid[sy][sx] = b'S';
+    grid[ey][ex] = b'E';
+
+    let mut s = String::new();
+    for row in grid {
+        writeln!(s, "{}", String::from_utf8(row).unwrap()).unwrap();
+    }
+    s
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let len = rng.range(0, 4);
+    let items = (0..len).map(|_| {
+        if depth < 4 && rng.chance(30) {
+            packet(rng, depth + 1)
+        } else {
+            rng.range(0, 10).to_string()
+        }
+    });
+    format!("[{}]", items.collect::<Vec<_>>().join(","))
+}
+
+fn day13(rng: &mut Rng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .join("\n")
+}
+
+fn day14(rng: &mut Rng, paths: usize) -> String {
+    let max_y = 10 + paths as i64;
+    let spread = 10 + paths as i64;
+    let mut s = String::new();
+    for _ in 0..paths {
+        let (mut x, mut y) = (500 + rng.range(-spread, spread), rng.range(1, max_y));
+        let mut points = vec![format!("{x},{y}")];
+        let mut horizontal = rng.chance(50);
+        for _ in 0..rng.range(1, 4) {
+            let len = rng.range(1, 8) * if rng.chance(50) { 1 } else { -1 };
+            if horizontal {
+                x += len;
+            } else {
+                y = (y + len).max(1);
+            }
+            horizontal = !horizontal;
+            points.push(format!("{x},{y}"));
+        }
+        writeln!(s, "{}", points.join(" -> ")).unwrap();
+    }
+    s
+}
+
+/// Part 2 searches `0..=4_000_000` for the single uncovered cell. Four sensors
+/// sit diagonally from a hidden cell with a range just short of it, which
+/// covers the whole search area but that cell; the other sensors are random
+/// and stay clear of it.
+fn day15(rng: &mut Rng, sensors: usize) -> String {
+    const MAX: i64 = 4_000_000;
+    let hidden = (rng.range(0, MAX), rng.range(0, MAX));
+    let reach = [hidden.0, MAX - hidden.0, hidden.1, MAX - hidden.1]
+        .into_iter()
+        .max()
+        .unwrap()
+        .max(1);
+
+    let mut s = String::new();
+    let mut sensor = |rng: &mut Rng, (sx, sy): (i64, i64), radius: i64| {
+        let dx = rng.range(-radius, radius);
+        let dy = (radius - dx.abs()) * if rng.chance(50) { 1 } else { -1 };
+        writeln!(
+            s,
+            "Sensor at x={sx}, y={sy}: closest beacon is at x={}, y={}",
+            sx + dx,
+            sy + dy
+        )
+        .unwrap();
+    };
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let pos = (hidden.0 + dx * reach, hidden.1 + dy * reach);
+        sensor(rng, pos, 2 * reach - 1);
+    }
+    for _ in 4..sensors {
+        let pos = loop {
+            let pos = (
+                rng.range(-MAX / 2, 3 * MAX / 2),
+                rng.range(-MAX / 2, 3 * MAX / 2),
+            );
+            if (pos.0 - hidden.0).abs() + (pos.1 - hidden.1).abs() > 1 {
+                break pos;
+            }
+        };
+        let to_hidden = (pos.0 - hidden.0).abs() + (pos.1 - hidden.1).abs();
+        let radius = rng.range(1, (to_hidden - 1).min(MAX / 2));
+        sensor(rng, pos, radius);
+    }
+    s
+}
+
+/// `AA` plus random two letter valves on a connected graph. At most fifteen
+/// valves have a flow, as in the puzzle.
+fn day16(rng: &mut Rng, valves: usize) -> String {
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .cartesian_product(b'A'..=b'Z')
+        .map(|(a, b)| String::from_utf8(vec![a, b]).unwrap())
+        .filter(|n| n != "AA")
+        .collect();
+    rng.shuffle(&mut names);
+    names.insert(0, String::from("AA"));
+    names.truncate(valves);
+
+    let mut tunnels = vec![BTreeSet::new(); valves];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for i in 1..valves {
+        let j = rng.below(i);
+        connect(i, j);
+    }
+    for _ in 0..valves / 4 {
+        let (a, b) = (rng.below(valves), rng.below(valves));
+        connect(a, b);
+    }
+
+    let mut flows = vec![0; valves];
+    let mut with_flow: Vec<usize> = (1..valves).collect();
+    rng.shuffle(&mut with_flow);
+    for &i in with_flow.iter().take(15.min(valves / 2).max(1)) {
+        flows[i] = rng.range(1, 25);
+    }
+
+    let mut lines: Vec<String> = (0..valves)
+        .map(|i| {
+            let to = tunnels[i].iter().map(|&j| names[j].as_str()).join(", ");
+            let tunnels = if tunnels[i].len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels} {to}",
+                names[i], flows[i]
+            )
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+fn day19(rng: &mut Rng, blueprints: usize) -> String {
+    let mut s = String::new();
+    for id in 1..=blueprints {
+        writeln!(
+            s,
+            "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+            Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            rng.range(2, 4),
+            rng.range(2, 4),
+            rng.range(2, 4),
+            rng.range(5, 20),
+            rng.range(2, 4),
+            rng.range(5, 20),
+        )
+        .unwrap();
+    }
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days;
+    use crate::solution::{Params, Part};
+    use test_case::test_case;
+
+    #[test]
+    fn test_deterministic() {
+        for day in days::DAYS {
+            assert_eq!(generate(day, 10, 7).unwrap(), generate(day, 10, 7).unwrap());
+        }
+        assert_ne!(generate(7, 10, 1).unwrap(), generate(7, 10, 2).unwrap());
+    }
+
+    #[test_case(1, 50, None)]
+    #[test_case(2, 50, None)]
+    #[test_case(3, 50, None)]
+    #[test_case(4, 50, None)]
+    #[test_case(5, 200, None)]
+    #[test_case(6, 500, None)]
+    #[test_case(7, 200, None)]
+    #[test_case(8, 30, None)]
+    #[test_case(9, 200, None)]
+    #[test_case(10, 500, None)]
+    #[test_case(11, 30, None)]
+    #[test_case(12, 40, None)]
+    #[test_case(13, 50, None)]
+    #[test_case(14, 20, None)]
+    #[test_case(15, 200, None)]
+    #[test_case(16, 60, None)]
+    #[test_case(16, 100, Some(Part::One))]
+    #[test_case(19, 3, Some(Part::One))]
+    fn test_solvable(day: u8, size: usize, part: Option<Part>) {
+        for seed in 0..3 {
+            let input = generate(day, size, seed).unwrap();
+            days::run(day, &input, part, &Params::default()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_day6_marker() {
+        let input = generate(6, 1000, 3).unwrap();
+        assert_eq!(days::day6::p2(&input), 1000);
+    }
+
+    #[test]
+    fn test_day15_hidden_cell() {
+        for seed in 0..5 {
+            let data = days::day15::gen(&generate(15, 200, seed).unwrap()).unwrap();
+            assert!(days::day15::p2(&data, 4_000_000).is_some());
+        }
+    }
+
+    #[test]
+    fn test_day7_big_disk() {
+        let input = generate(7, 5000, 0).unwrap();
+        assert!(days::run(7, &input, None, &Params::default()).is_err());
+        let params = Params::parse(&["disk_size=3000000000", "needed_space=700000000"]).unwrap();
+        days::run(7, &input, None, &params).unwrap();
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(generate(19, 256, 0).is_err());
+        assert!(generate(16, 677, 0).is_err());
+        assert!(generate(15, 3, 0).is_err());
+        assert!(generate(18, 10, 0).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use advent_2022::bigboy;
+use advent_2022::days::day15::{self, Pos};
+use advent_2022::days::day7::Fs;
+use advent_2022::days::{self, DAYS};
+use advent_2022::input::{self, Source};
+use advent_2022::report::{Machine, Report, CSV_HEADER};
+use advent_2022::solution::{Params, Part};
+use advent_2022::verify::{self, Answers, Status};
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or `all` of them
+    Run {
+        #[arg(value_parser = parse_days)]
+        day: Days,
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/N.txt`
+        #[arg(long)]
+        input: Option<String>,
+        /// Only run this part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// A day specific `key=value` setting, such as `disk_size=3000000000` for day 7
+        #[arg(long = "set", value_name = "KEY=VALUE")]
+        params: Vec<String>,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Append the report to this file instead of printing it
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Check the answers for `inputs/N.txt` against an answers file
+    Verify {
+        #[arg(value_parser = parse_days, default_value = "all")]
+        day: Days,
+        /// Only check this part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// The expected answers
+        #[arg(long, default_value = verify::DEFAULT_PATH)]
+        answers: PathBuf,
+    },
+    /// Generate a large random input for a day
+    GenInput {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// How big the input is, the unit depends on the day
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Write the input to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Print the day 7 filesystem, or save it as JSON to load it back later
+    Disk {
+        /// Read the transcript from this file, or `-` for stdin, instead of `inputs/7.txt`
+        #[arg(long, conflicts_with = "load")]
+        input: Option<String>,
+        /// Read the filesystem from a JSON file written by `--save` instead of a transcript
+        #[arg(long)]
+        load: Option<PathBuf>,
+        /// Write the filesystem as JSON to this file instead of printing it
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Print every directory with its total size, like `du`, instead of the tree
+        #[arg(long, conflicts_with = "save")]
+        du: bool,
+    },
+    /// Draw the day 15 sensors and what they cover, or count it
+    Sensors {
+        /// Read the input from this file, or `-` for stdin, instead of `inputs/15.txt`
+        #[arg(long)]
+        input: Option<String>,
+        /// The top left corner of the window, as `x,y`
+        #[arg(long, default_value = "0,0", allow_hyphen_values = true)]
+        min: Pos,
+        /// The bottom right corner of the window, included
+        #[arg(long, default_value = "20,20", allow_hyphen_values = true)]
+        max: Pos,
+        /// Draw one character or pixel for every `scale` by `scale` cells
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(i64).range(1..))]
+        scale: i64,
+        /// Write a PPM image to this file instead of printing the drawing
+        #[arg(long)]
+        ppm: Option<PathBuf>,
+        /// Print the covered area and the uncovered cells of the window instead
+        #[arg(long, conflicts_with_all = ["ppm", "scale"])]
+        stats: bool,
+        /// Refuse lines with text after the beacon
+        #[arg(long)]
+        strict: bool,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human readable
+    Text,
+    /// One JSON object per day
+    Json,
+    /// One CSV row per day, with a header
+    Csv,
+}
+
+#[derive(Clone)]
+enum Days {
+    All,
+    One(u8),
+}
+
+impl Days {
+    fn to_vec(&self) -> Vec<u8> {
+        match self {
+            Days::All => DAYS.to_vec(),
+            Days::One(day) => vec![*day],
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8> {
+    let day = s.parse().with_context(|| format!("Invalid day `{s}`"))?;
+    if !DAYS.contains(&day) {
+        return Err(anyhow!("Day {day} has no solution"));
+    }
+    Ok(day)
+}
+
+fn parse_days(s: &str) -> Result<Days> {
+    if s == "all" {
+        return Ok(Days::All);
+    }
+    parse_day(s).map(Days::One)
+}
+
+fn parse_part(s: &str) -> Result<Part> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(anyhow!("Part must be 1 or 2, got `{s}`")),
+    }
+}
+
+fn open_output(output: &Option<PathBuf>, format: Format) -> Result<Box<dyn Write>> {
+    let Some(path) = output else {
+        let mut out = io::stdout();
+        if format == Format::Csv {
+            writeln!(out, "{CSV_HEADER}")?;
+        }
+        return Ok(Box::new(out));
+    };
+    let is_new = fs::metadata(path).map_or(true, |m| m.len() == 0);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open report file {}", path.display()))?;
+    if format == Format::Csv && is_new {
+        writeln!(file, "{CSV_HEADER}")?;
+    }
+    Ok(Box::new(file))
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            input,
+            part,
+            params,
+            format,
+            output,
+        } => {
+            let source = match (&day, input) {
+                (_, None) => Source::Default,
+                (Days::One(_), Some(arg)) => Source::from_arg(&arg),
+                (Days::All, Some(_)) => return Err(anyhow!("`--input` needs a single day")),
+            };
+            let params = Params::parse(&params)?;
+            if matches!(day, Days::All) && !params.is_empty() {
+                return Err(anyhow!("`--set` needs a single day"));
+            }
+            let machine = Machine::current();
+            let mut out = open_output(&output, format)?;
+            for day in day.to_vec() {
+                let input = input::read(day, &source)?;
+                let run = days::run(day, &input, part, &params)?;
+                match format {
+                    Format::Text => writeln!(out, "{run}")?,
+                    Format::Json => {
+                        writeln!(out, "{}", Report::new(&run, &input, &machine).to_json())?
+                    }
+                    Format::Csv => {
+                        writeln!(out, "{}", Report::new(&run, &input, &machine).to_csv())?
+                    }
+                }
+            }
+        }
+        Command::Verify { day, part, answers } => {
+            let answers = Answers::load(&answers)?;
+            let (mut passed, mut failed, mut missing) = (0, 0, 0);
+            for day in day.to_vec() {
+                let checks = input::read(day, &Source::Default)
+                    .and_then(|input| days::run(day, &input, part, &Params::default()))
+                    .map(|run| verify::check(&run, &answers));
+                let checks = match checks {
+                    Ok(checks) => checks,
+                    Err(e) => {
+                        println!("day {day:>2}         ERROR    {e:#}");
+                        failed += 1;
+                        continue;
+                    }
+                };
+                for check in checks {
+                    println!("{check}");
+                    match check.status {
+                        Status::Pass => passed += 1,
+                        Status::Fail { .. } => failed += 1,
+                        Status::Missing { .. } => missing += 1,
+                    }
+                }
+            }
+            println!("\n{passed} passed, {failed} failed, {missing} missing");
+            if failed > 0 {
+                return Err(anyhow!(
+                    "verification failed: {failed} wrong or erroring answers"
+                ));
+            }
+        }
+        Command::GenInput {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let input = bigboy::generate(day, size, seed)?;
+            match output {
+                Some(path) => fs::write(&path, input)
+                    .with_context(|| format!("Failed to write {}", path.display()))?,
+                None => io::stdout().write_all(input.as_bytes())?,
+            }
+        }
+        Command::Disk {
+            input,
+            load,
+            save,
+            du,
+        } => {
+            let fs = match load {
+                Some(path) => fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))
+                    .and_then(|json| Fs::from_json(&json))
+                    .with_context(|| format!("Invalid filesystem in {}", path.display()))?,
+                None => {
+                    let source = input.map_or(Source::Default, |arg| Source::from_arg(&arg));
+                    Fs::parse(&input::read(7, &source)?)?
+                }
+            };
+            if let Some(path) = save {
+                fs::write(&path, fs.to_json())
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            } else if du {
+                print!("{}", fs.du());
+            } else {
+                print!("{}", fs.tree());
+            }
+        }
+        Command::Sensors {
+            input,
+            min,
+            max,
+            scale,
+            ppm,
+            stats,
+            strict,
+        } => {
+            if min.0 > max.0 || min.1 > max.1 {
+                return Err(anyhow!("`--min` {min} is past `--max` {max}"));
+            }
+            let source = input.map_or(Source::Default, |arg| Source::from_arg(&arg));
+            let input = input::read(15, &source)?;
+            let data = if strict {
+                day15::gen_strict(&input)?
+            } else {
+                day15::gen(&input)?
+            };
+            if stats {
+                let uncovered = day15::uncovered(&data, min, max);
+                println!("covered: {}", day15::covered_area(&data, min, max));
+                println!("uncovered: {}", uncovered.len());
+                for pos in uncovered {
+                    println!("{pos}");
+                }
+            } else if let Some(path) = ppm {
+                fs::write(&path, day15::render_ppm(&data, min, max, scale))
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            } else {
+                print!("{}", day15::render(&data, min, max, scale));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::solution::{self, Params, Part, Run};
+use anyhow::{anyhow, Result};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day19;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Every day that has a solution, in calendar order.
+pub const DAYS: [u8; 17] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 19];
+
+pub fn run(day: u8, input: &str, part: Option<Part>, params: &Params) -> Result<Run> {
+    match day {
+        1 => solution::run::<day1::Day1>(day, input, part, params),
+        2 => solution::run::<day2::Day2>(day, input, part, params),
+        3 => solution::run::<day3::Day3>(day, input, part, params),
+        4 => solution::run::<day4::Day4>(day, input, part, params),
+        5 => solution::run::<day5::Day5>(day, input, part, params),
+        6 => solution::run::<day6::Day6>(day, input, part, params),
+        7 => solution::run::<day7::Day7>(day, input, part, params),
+        8 => solution::run::<day8::Day8>(day, input, part, params),
+        9 => solution::run::<day9::Day9>(day, input, part, params),
+        10 => solution::run::<day10::Day10>(day, input, part, params),
+        11 => solution::run::<day11::Day11>(day, input, part, params),
+        12 => solution::run::<day12::Day12>(day, input, part, params),
+        13 => solution::run::<day13::Day13>(day, input, part, params),
+        14 => solution::run::<day14::Day14>(day, input, part, params),
+        15 => solution::run::<day15::Day15>(day, input, part, params),
+        16 => solution::run::<day16::Day16>(day, input, part, params),
+        19 => solution::run::<day19::Day19>(day, input, part, params),
+        _ => Err(anyhow!("Day {day} has no solution")),
+    }
+}
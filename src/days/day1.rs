@@ -1,6 +1,9 @@
-const INPUT: &str = include_str!("../../inputs/1.txt");
+use crate::solution::Solution;
+use anyhow::Result;
 
-fn p1(input: &str) -> u64 {
+pub struct Day1;
+
+pub fn p1(input: &str) -> u64 {
     input
         .split("\n\n")
         .map(|elf| elf.lines().map(|l| l.parse::<u64>().unwrap()).sum::<u64>())
@@ -8,7 +11,7 @@ fn p1(input: &str) -> u64 {
         .unwrap()
 }
 
-fn p2(input: &str) -> u64 {
+pub fn p2(input: &str) -> u64 {
     let sums = input
         .split("\n\n")
         .map(|elf| elf.lines().map(|l| l.parse::<u64>().unwrap()).sum::<u64>());
@@ -24,9 +27,22 @@ fn p2(input: &str) -> u64 {
     top_three.iter().sum::<u64>()
 }
 
-fn main() {
-    println!("{}", p1(INPUT));
-    println!("{}", p2(INPUT));
+impl Solution for Day1 {
+    type Input = String;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> u64 {
+        p1(input)
+    }
+
+    fn part2(input: &String) -> u64 {
+        p2(input)
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
-use std::fs;
-use std::time::Instant;
+use crate::solution::Solution;
+use anyhow::Result;
 
-struct State {
+pub struct Day10;
+
+pub struct State {
     x: i32,
     cycle: usize,
     operation: Option<(i32, usize)>,
@@ -21,7 +23,7 @@ impl std::fmt::Debug for State {
 }
 
 impl State {
-    fn new(instr: &[Option<i32>]) -> State {
+    pub fn new(instr: &[Option<i32>]) -> State {
         State {
             cycle: 0,
             operation: None,
@@ -32,7 +34,7 @@ impl State {
     }
 }
 
-fn gen(input: &str) -> Vec<Option<i32>> {
+pub fn gen(input: &str) -> Vec<Option<i32>> {
     input
         .lines()
         .map(|line| {
@@ -42,11 +44,11 @@ fn gen(input: &str) -> Vec<Option<i32>> {
                 None
             }
         })
-        .chain(std::iter::repeat(None).take(2))
+        .chain(std::iter::repeat_n(None, 2))
         .collect()
 }
 
-fn p1(mut state: State) -> i32 {
+pub fn p1(mut state: State) -> i32 {
     let mut instrs = state.instr.iter();
     loop {
         state.cycle += 1;
@@ -56,7 +58,7 @@ fn p1(mut state: State) -> i32 {
                 Some(instr) => state.operation = instr.map(|val| (val, 1)),
             };
         }
-        if (state.cycle + 20) % 40 == 0 {
+        if (state.cycle + 20).is_multiple_of(40) {
             state.acc += state.cycle as i32 * state.x;
         }
         if let Some((val, cycles_left)) = &mut state.operation {
@@ -92,14 +94,14 @@ impl std::fmt::Display for Screen {
     }
 }
 
-fn p2(mut state: State) -> String {
+pub fn p2(mut state: State) -> String {
     let mut instrs = state.instr.iter();
     let mut pixels = Screen([false; 240]);
 
     loop {
         state.cycle += 1;
         let pixel_idx = state.cycle - 1;
-        if pixel_idx > 240 {
+        if pixel_idx >= 240 {
             break;
         }
         if state.operation.is_none() {
@@ -124,23 +126,22 @@ fn p2(mut state: State) -> String {
     format!("{pixels}")
 }
 
-fn main() {
-    let path = "inputs/10.txt";
-    let input = gen(&fs::read_to_string(path).unwrap());
-    let s1 = State::new(&input);
-    let s2 = State::new(&input);
+impl Solution for Day10 {
+    type Input = Vec<Option<i32>>;
+    type Output1 = i32;
+    type Output2 = String;
 
-    let i11 = Instant::now();
-    let res1 = p1(s1);
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+    fn parse(input: &str) -> Result<Vec<Option<i32>>> {
+        Ok(gen(input))
+    }
 
-    println!("-----");
+    fn part1(instr: &Vec<Option<i32>>) -> i32 {
+        p1(State::new(instr))
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2(s2);
-    let i22 = Instant::now();
-    println!("gold:\n{}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(instr: &Vec<Option<i32>>) -> String {
+        p2(State::new(instr))
+    }
 }
 
 #[cfg(test)]
@@ -313,4 +314,12 @@ noop");
 #######.......#######.......#######....."
         );
     }
+
+    #[test]
+    fn longer_than_screen() {
+        // The program keeps going after the last pixel has been drawn.
+        let opes = gen(&"noop\n".repeat(300));
+        let row = format!("###{}", ".".repeat(37));
+        assert_eq!(p2(State::new(&opes)), vec![row; 6].join("\n"));
+    }
 }
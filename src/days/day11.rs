@@ -1,5 +1,7 @@
-use std::fs;
-use std::time::Instant;
+use crate::solution::Solution;
+use anyhow::Result;
+
+pub struct Day11;
 
 #[derive(Clone, Debug)]
 enum Operation {
@@ -8,7 +10,7 @@ enum Operation {
 }
 
 #[derive(Clone, Debug)]
-struct Monkey {
+pub struct Monkey {
     inspected: usize,
     items: Vec<u64>,
     ope: Operation,
@@ -18,7 +20,7 @@ struct Monkey {
 
 impl Monkey {}
 
-fn gen(input: &str) -> Vec<Monkey> {
+pub fn gen(input: &str) -> Vec<Monkey> {
     input
         .split("\n\n")
         .map(|s| {
@@ -94,7 +96,7 @@ fn monkey_round(monkeys: &mut [Monkey]) {
                 Operation::Mul(Some(val)) => item * val,
                 Operation::Mul(None) => item.pow(2),
             } / 3u64;
-            if worry % monkey.divisible_by == 0 {
+            if worry.is_multiple_of(monkey.divisible_by) {
                 monkeys[monkey.throw_to.1].items.push(worry);
             } else {
                 monkeys[monkey.throw_to.0].items.push(worry);
@@ -116,7 +118,7 @@ fn monkey_round2(monkeys: &mut [Monkey], ppmc: u64) {
                 Operation::Mul(Some(val)) => item * val,
                 Operation::Mul(None) => item.pow(2),
             } % ppmc;
-            if worry % monkey.divisible_by == 0 {
+            if worry.is_multiple_of(monkey.divisible_by) {
                 let to = monkey.throw_to.1;
                 monkeys[to].items.push(worry);
             } else {
@@ -128,7 +130,7 @@ fn monkey_round2(monkeys: &mut [Monkey], ppmc: u64) {
     }
 }
 
-fn p1(mut monkeys: Vec<Monkey>) -> usize {
+pub fn p1(mut monkeys: Vec<Monkey>) -> usize {
     for _ in 0..20 {
         monkey_round(&mut monkeys);
     }
@@ -137,7 +139,7 @@ fn p1(mut monkeys: Vec<Monkey>) -> usize {
     vals.pop().unwrap() * vals.pop().unwrap()
 }
 
-fn p2(mut monkeys: Vec<Monkey>) -> usize {
+pub fn p2(mut monkeys: Vec<Monkey>) -> usize {
     let prod = monkeys.iter().map(|m| m.divisible_by).product();
     for _ in 0..10000 {
         monkey_round2(&mut monkeys, prod);
@@ -147,22 +149,22 @@ fn p2(mut monkeys: Vec<Monkey>) -> usize {
     vals.pop().unwrap() * vals.pop().unwrap()
 }
 
-fn main() {
-    let path = "inputs/11.txt";
-    let input = fs::read_to_string(path).unwrap();
-    let monkeys = gen(&input);
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let i11 = Instant::now();
-    let res1 = p1(monkeys.clone());
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+    fn parse(input: &str) -> Result<Vec<Monkey>> {
+        Ok(gen(input))
+    }
 
-    println!("-----");
+    fn part1(monkeys: &Vec<Monkey>) -> usize {
+        p1(monkeys.clone())
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2(monkeys);
-    let i22 = Instant::now();
-    println!("gold: {}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(monkeys: &Vec<Monkey>) -> usize {
+        p2(monkeys.clone())
+    }
 }
 
 #[cfg(test)]
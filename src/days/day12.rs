@@ -0,0 +1,438 @@
+use crate::matrix::{GridError, Matrix};
+use crate::search;
+use crate::solution::{Params, Solution};
+use anyhow::Result;
+use std::cmp::Ordering;
+use std::fmt;
+
+pub struct Day12;
+
+/// The heights from 1 to 26, the start and the end.
+pub type Heightmap = (Matrix<u8>, (usize, usize), (usize, usize));
+
+pub fn gen(input: &str) -> Result<Heightmap, GridError> {
+    let (mat, [start_pos, end_pos]) =
+        Matrix::from_str_grid_with_markers(input, ['S', 'E'], |c| match c {
+            'S' => Some(1),
+            'E' => Some(26),
+            'a'..='z' => Some(c as u8 - b'a' + 1),
+            _ => None,
+        })?;
+    Ok((mat, start_pos, end_pos))
+}
+
+/// How the climber may move between neighbouring cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rules {
+    /// How much higher the next cell may be.
+    pub max_up: u8,
+    /// How much lower the next cell may be, `None` for any drop.
+    pub max_down: Option<u8>,
+    /// Whether diagonal steps are allowed.
+    pub diagonals: bool,
+    /// What each unit of height difference adds to the cost of a step, on top
+    /// of the 1 every step costs.
+    pub climb_cost: usize,
+}
+
+impl Default for Rules {
+    /// The puzzle's rules: at most one up, any drop, no diagonals, every step costs 1.
+    fn default() -> Self {
+        Rules {
+            max_up: 1,
+            max_down: None,
+            diagonals: false,
+            climb_cost: 0,
+        }
+    }
+}
+
+impl Rules {
+    pub fn allows(&self, from: u8, to: u8) -> bool {
+        if to >= from {
+            to - from <= self.max_up
+        } else {
+            self.max_down.is_none_or(|max| from - to <= max)
+        }
+    }
+
+    pub fn cost(&self, from: u8, to: u8) -> usize {
+        1 + self.climb_cost * from.abs_diff(to) as usize
+    }
+
+    fn neighbors(&self, mat: &Matrix<u8>, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+        if self.diagonals {
+            mat.neighbor_indices(x, y)
+        } else {
+            mat.rook_neighbor_indices(x, y).collect()
+        }
+    }
+
+    /// The cells that can be reached from `pos`, with the cost of the step.
+    fn moves(&self, mat: &Matrix<u8>, pos: (usize, usize)) -> Vec<((usize, usize), usize)> {
+        let here = mat[pos];
+        self.neighbors(mat, pos)
+            .into_iter()
+            .filter(|&next| self.allows(here, mat[next]))
+            .map(|next| (next, self.cost(here, mat[next])))
+            .collect()
+    }
+
+    /// The cells `pos` can be reached from, with the cost of the step.
+    fn moves_back(&self, mat: &Matrix<u8>, pos: (usize, usize)) -> Vec<((usize, usize), usize)> {
+        let here = mat[pos];
+        self.neighbors(mat, pos)
+            .into_iter()
+            .filter(|&prev| self.allows(mat[prev], here))
+            .map(|prev| (prev, self.cost(mat[prev], here)))
+            .collect()
+    }
+}
+
+/// One of the cheapest routes from `start_pos` to `end_pos`, both included.
+pub fn route(
+    mat: &Matrix<u8>,
+    rules: &Rules,
+    start_pos: (usize, usize),
+    end_pos: (usize, usize),
+) -> Option<Vec<(usize, usize)>> {
+    search::dijkstra([start_pos], |&pos| rules.moves(mat, pos)).path_to(&end_pos)
+}
+
+/// The cost of getting from `start_pos` to `end_pos`, which is the number of
+/// steps with the default rules, if there is a way up at all.
+pub fn path_len(
+    mat: &Matrix<u8>,
+    rules: &Rules,
+    start_pos: (usize, usize),
+    end_pos: (usize, usize),
+) -> Option<usize> {
+    search::dijkstra([start_pos], |&pos| rules.moves(mat, pos)).distance(&end_pos)
+}
+
+/// Draws a route like the puzzle does: every cell on it points to the next one
+/// with `>`, `<`, `^` or `v`, or `/` and `\\` for diagonal steps. The last one
+/// is `E` and the rest is `.`.
+pub fn render_route(mat: &Matrix<u8>, route: &[(usize, usize)]) -> String {
+    let mut canvas = Matrix::new_with(mat.width(), mat.height(), || '.');
+    for step in route.windows(2) {
+        let ((x, y), (nx, ny)) = (step[0], step[1]);
+        canvas[(x, y)] = match (nx.cmp(&x), ny.cmp(&y)) {
+            (Ordering::Greater, Ordering::Equal) => '>',
+            (Ordering::Less, Ordering::Equal) => '<',
+            (Ordering::Equal, Ordering::Less) => '^',
+            (Ordering::Equal, _) => 'v',
+            (Ordering::Greater, Ordering::Less) | (Ordering::Less, Ordering::Greater) => '/',
+            _ => '\\',
+        };
+    }
+    if let Some(&end) = route.last() {
+        canvas[end] = 'E';
+    }
+    canvas
+        .rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// What it costs from every cell to `end_pos`, or `None` where it can't be
+/// reached. One search backwards from the end covers the whole map.
+pub fn distance_field(
+    mat: &Matrix<u8>,
+    rules: &Rules,
+    end_pos: (usize, usize),
+) -> Matrix<Option<usize>> {
+    let search = search::dijkstra([end_pos], |&pos| rules.moves_back(mat, pos));
+    Matrix::new(
+        mat.positions().map(|pos| search.distance(&pos)),
+        mat.width(),
+        mat.height(),
+    )
+}
+
+/// The shortest climb from any cell at the lowest height, given the
+/// [`distance_field`] to the end.
+pub fn shortest_start(mat: &Matrix<u8>, field: &Matrix<Option<usize>>) -> Option<usize> {
+    mat.positions()
+        .filter(|&pos| mat[pos] == 1)
+        .filter_map(|pos| field[pos])
+        .min()
+}
+
+/// The shortest climb to `end_pos` from any cell at the lowest height.
+pub fn p2(mat: &Matrix<u8>, rules: &Rules, end_pos: (usize, usize)) -> Option<usize> {
+    shortest_start(mat, &distance_field(mat, rules, end_pos))
+}
+
+/// The cells at the lowest height that have no way up, given the
+/// [`distance_field`] to the end.
+pub fn unreachable_starts(mat: &Matrix<u8>, field: &Matrix<Option<usize>>) -> Vec<(usize, usize)> {
+    mat.positions()
+        .filter(|&pos| mat[pos] == 1 && field[pos].is_none())
+        .collect()
+}
+
+/// An answer and the starting cells that can't get to `E`, or only those when
+/// none of them can, so that a bad input says so instead of printing a bogus
+/// number. `{:#}` also lists the ones left behind next to an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Climb {
+    Steps(usize, Vec<(usize, usize)>),
+    Unreachable(Vec<(usize, usize)>),
+}
+
+fn write_starts(f: &mut fmt::Formatter<'_>, starts: &[(usize, usize)]) -> fmt::Result {
+    const SHOWN: usize = 5;
+    let shown = starts
+        .iter()
+        .take(SHOWN)
+        .map(|(x, y)| format!("({x}, {y})"));
+    write!(f, "{}", shown.collect::<Vec<_>>().join(", "))?;
+    if starts.len() > SHOWN {
+        write!(f, " and {} more", starts.len() - SHOWN)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Climb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Climb::Steps(steps, stuck) if f.alternate() && !stuck.is_empty() => {
+                write!(f, "{steps}, E can't be reached from ")?;
+                write_starts(f, stuck)
+            }
+            Climb::Steps(steps, _) => write!(f, "{steps}"),
+            Climb::Unreachable(starts) => {
+                write!(f, "unreachable, E can't be reached from ")?;
+                write_starts(f, starts)
+            }
+        }
+    }
+}
+
+impl Solution for Day12 {
+    type Input = (Heightmap, Rules);
+    type Output1 = Climb;
+    type Output2 = Climb;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((gen(input)?, Rules::default()))
+    }
+
+    /// Accepts `max_up`, `max_down`, `diagonals` and `climb_cost` to change the rules.
+    fn configure((_, rules): &mut Self::Input, params: &Params) -> Result<()> {
+        params.only(&["max_up", "max_down", "diagonals", "climb_cost"])?;
+        if let Some(max_up) = params.get("max_up")? {
+            rules.max_up = max_up;
+        }
+        if let Some(max_down) = params.get("max_down")? {
+            rules.max_down = Some(max_down);
+        }
+        if let Some(diagonals) = params.get("diagonals")? {
+            rules.diagonals = diagonals;
+        }
+        if let Some(climb_cost) = params.get("climb_cost")? {
+            rules.climb_cost = climb_cost;
+        }
+        Ok(())
+    }
+
+    fn part1(((mat, start_pos, end_pos), rules): &Self::Input) -> Climb {
+        match path_len(mat, rules, *start_pos, *end_pos) {
+            Some(steps) => Climb::Steps(steps, Vec::new()),
+            None => Climb::Unreachable(vec![*start_pos]),
+        }
+    }
+
+    fn part2(((mat, _, end_pos), rules): &Self::Input) -> Climb {
+        let field = distance_field(mat, rules, *end_pos);
+        let stuck = unreachable_starts(mat, &field);
+        match shortest_start(mat, &field) {
+            Some(steps) => Climb::Steps(steps, stuck),
+            None => Climb::Unreachable(stuck),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod d12 {
+    use super::*;
+
+    pub(crate) const SAMPLE: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn t1() {
+        let (mat, start_pos, end_pos) = gen(SAMPLE).unwrap();
+        assert_eq!(
+            path_len(&mat, &Rules::default(), start_pos, end_pos),
+            Some(31)
+        )
+    }
+
+    #[test]
+    fn t2() {
+        let (mat, _, end_pos) = gen(SAMPLE).unwrap();
+        assert_eq!(p2(&mat, &Rules::default(), end_pos), Some(29))
+    }
+
+    #[test]
+    fn route_sample() {
+        let (mat, start_pos, end_pos) = gen(SAMPLE).unwrap();
+        let route = route(&mat, &Rules::default(), start_pos, end_pos).unwrap();
+        assert_eq!(route.len(), 32);
+        assert_eq!((route[0], route[31]), (start_pos, end_pos));
+        let drawing = render_route(&mat, &route);
+        assert_eq!(drawing.matches(['>', '<', '^', 'v']).count(), 31);
+        assert_eq!(drawing.lines().nth(2).unwrap().find('E'), Some(5));
+    }
+
+    #[test]
+    fn render_snake() {
+        let (mat, start_pos, end_pos) = gen("Sabcdefghijklm
+Ezyxwvutsrqpon")
+        .unwrap();
+        let route = route(&mat, &Rules::default(), start_pos, end_pos).unwrap();
+        assert_eq!(
+            render_route(&mat, &route),
+            ">>>>>>>>>>>>>v
+E<<<<<<<<<<<<<
+"
+        );
+    }
+
+    #[test]
+    fn t2_anywhere() {
+        // The only way up is from the `a` at the far right, not from column 0
+        let (mat, _, end_pos) = gen("SEzyxwvutsrqponmlkjihgfedcba").unwrap();
+        assert_eq!(p2(&mat, &Rules::default(), end_pos), Some(26))
+    }
+
+    #[test]
+    fn walled_off() {
+        // The `z` next to `E` is now an `m`, too low to climb from.
+        let input = SAMPLE.replace("accszExk", "accsmExk");
+        let input = input.as_str();
+        let (mat, start_pos, end_pos) = gen(input).unwrap();
+        assert_eq!(path_len(&mat, &Rules::default(), start_pos, end_pos), None);
+        assert_eq!(p2(&mat, &Rules::default(), end_pos), None);
+        let field = distance_field(&mat, &Rules::default(), end_pos);
+        assert_eq!(unreachable_starts(&mat, &field).len(), 6);
+
+        let input = Day12::parse(input).unwrap();
+        assert_eq!(
+            Day12::part1(&input).to_string(),
+            "unreachable, E can't be reached from (0, 0)"
+        );
+        assert_eq!(
+            Day12::part2(&input).to_string(),
+            "unreachable, E can't be reached from (0, 0), (1, 0), (0, 1), (0, 2), (0, 3) and 1 more"
+        );
+    }
+
+    #[test]
+    fn some_unreachable() {
+        let input = "SbcdefghijklmnopqrstuvwxyzE
+zzzzzzzzzzzzzzzzzzzzzzzzzzz
+zazzzzzzzzzzzzzzzzzzzzzzzzz";
+        let (mat, _, end_pos) = gen(input).unwrap();
+        assert_eq!(p2(&mat, &Rules::default(), end_pos), Some(26));
+        let field = distance_field(&mat, &Rules::default(), end_pos);
+        assert_eq!(unreachable_starts(&mat, &field), [(1, 2)]);
+
+        let input = Day12::parse(input).unwrap();
+        let climb = Day12::part2(&input);
+        assert_eq!(climb, Climb::Steps(26, vec![(1, 2)]));
+        assert_eq!(climb.to_string(), "26");
+        assert_eq!(format!("{climb:#}"), "26, E can't be reached from (1, 2)");
+    }
+
+    #[test]
+    fn rules() {
+        let rules = Rules::default();
+        assert!(rules.allows(1, 2) && rules.allows(26, 1) && !rules.allows(1, 3));
+        let rules = Rules {
+            max_up: 2,
+            max_down: Some(1),
+            ..Rules::default()
+        };
+        assert!(rules.allows(1, 3) && rules.allows(5, 4) && !rules.allows(5, 3));
+        assert_eq!(Rules::default().cost(1, 20), 1);
+    }
+
+    #[test]
+    fn custom_rules() {
+        let (mat, start_pos, end_pos) = gen("Sabcdefghijklm
+Ezyxwvutsrqpon")
+        .unwrap();
+        let costly = Rules {
+            climb_cost: 1,
+            ..Rules::default()
+        };
+        // 27 steps, climbing 25 in total
+        assert_eq!(path_len(&mat, &costly, start_pos, end_pos), Some(52));
+
+        // The only way has a dip just before the end
+        let (dip, dip_start, dip_end) = gen("SbcdefghijklmnopqrstuvwxyzyzE").unwrap();
+        let no_drops = Rules {
+            max_down: Some(0),
+            ..Rules::default()
+        };
+        assert_eq!(path_len(&dip, &no_drops, dip_start, dip_end), None);
+        let small_drops = Rules {
+            max_down: Some(1),
+            ..Rules::default()
+        };
+        assert_eq!(path_len(&dip, &small_drops, dip_start, dip_end), Some(28));
+        let strict = Rules {
+            max_up: 0,
+            ..Rules::default()
+        };
+        assert_eq!(path_len(&mat, &strict, start_pos, end_pos), None);
+    }
+
+    #[test]
+    fn diagonal_rules() {
+        let (mat, start_pos, end_pos) = gen(SAMPLE).unwrap();
+        let diagonal = Rules {
+            diagonals: true,
+            ..Rules::default()
+        };
+        let route = route(&mat, &diagonal, start_pos, end_pos).unwrap();
+        assert_eq!(route.len(), 28);
+        assert!(render_route(&mat, &route).contains(['/', '\\']));
+    }
+
+    #[test]
+    fn configure() {
+        let mut input = Day12::parse("SbcdefghijklmnopqrstuvwxyzE").unwrap();
+        let params = Params::parse(&["max_up=2", "diagonals=true", "climb_cost=3"]).unwrap();
+        Day12::configure(&mut input, &params).unwrap();
+        assert_eq!(
+            input.1,
+            Rules {
+                max_up: 2,
+                max_down: None,
+                diagonals: true,
+                climb_cost: 3
+            }
+        );
+        let params = Params::parse(&["max_up=-1"]).unwrap();
+        assert!(Day12::configure(&mut input, &params).is_err());
+        let params = Params::parse(&["teleport=1"]).unwrap();
+        assert!(Day12::configure(&mut input, &params).is_err());
+    }
+
+    #[test]
+    fn distances() {
+        let (mat, start_pos, end_pos) = gen(SAMPLE).unwrap();
+        let field = distance_field(&mat, &Rules::default(), end_pos);
+        assert_eq!(field[start_pos], Some(31));
+        assert_eq!(field[end_pos], Some(0));
+        assert_eq!(field[(0, 4)], Some(29));
+        assert!(mat.positions().all(|pos| field[pos].is_some()));
+    }
+}
@@ -1,14 +1,16 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use nom::{
     branch::alt, bytes::complete::tag, character::complete::i32, combinator::map,
     multi::separated_list0, sequence::delimited, IResult,
 };
 use std::cmp::Ordering;
-use std::fs;
 use std::iter::once;
-use std::time::Instant;
+
+pub struct Day13;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-enum ListEl {
+pub enum ListEl {
     Num(i32),
     L(Vec<ListEl>),
 }
@@ -39,7 +41,7 @@ fn parse_list_inside(s: &str) -> IResult<&str, Vec<ListEl>> {
     separated_list0(tag(","), alt((map(i32, ListEl::Num), parse_list)))(s)
 }
 
-fn gen(input: &str) -> Vec<(ListEl, ListEl)> {
+pub fn gen(input: &str) -> Vec<(ListEl, ListEl)> {
     input
         .split("\n\n")
         .map(|g| {
@@ -49,7 +51,7 @@ fn gen(input: &str) -> Vec<(ListEl, ListEl)> {
         .collect()
 }
 
-fn p1(data: &[(ListEl, ListEl)]) -> usize {
+pub fn p1(data: &[(ListEl, ListEl)]) -> usize {
     data.iter()
         .enumerate()
         .filter(|(_, (l1, l2))| l1.cmp(l2).is_lt())
@@ -57,7 +59,7 @@ fn p1(data: &[(ListEl, ListEl)]) -> usize {
         .sum()
 }
 
-fn p2(data: Vec<(ListEl, ListEl)>) -> usize {
+pub fn p2(data: Vec<(ListEl, ListEl)>) -> usize {
     let k1 = parse_list("[[2]]").unwrap().1;
     let k2 = parse_list("[[6]]").unwrap().1;
     let mut data: Vec<_> = data
@@ -72,26 +74,22 @@ fn p2(data: Vec<(ListEl, ListEl)>) -> usize {
     p1 * p2
 }
 
-fn main() {
-    let path = "inputs/13.txt";
-    let input = fs::read_to_string(path).unwrap();
-
-    let in1 = Instant::now();
-    let data = gen(&input);
-    let in0 = Instant::now();
-    println!("Input parsed in: {:?}", in0.duration_since(in1));
+impl Solution for Day13 {
+    type Input = Vec<(ListEl, ListEl)>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let i11 = Instant::now();
-    let res1 = p1(&data);
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+    fn parse(input: &str) -> Result<Vec<(ListEl, ListEl)>> {
+        Ok(gen(input))
+    }
 
-    println!("-----");
+    fn part1(data: &Vec<(ListEl, ListEl)>) -> usize {
+        p1(data)
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2(data);
-    let i22 = Instant::now();
-    println!("gold: {:?}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(data: &Vec<(ListEl, ListEl)>) -> usize {
+        p2(data.clone())
+    }
 }
 
 #[cfg(test)]
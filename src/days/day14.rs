@@ -0,0 +1,143 @@
+use crate::grid::{Direction, DynamicGrid, Grid, Pos};
+use crate::solution::Solution;
+use anyhow::Result;
+use nom::{
+    bytes::complete::tag, character::complete::i64, combinator::map, multi::separated_list1,
+    sequence::separated_pair, IResult,
+};
+use std::cmp::min;
+
+pub struct Day14;
+
+#[derive(Clone, PartialEq)]
+pub enum Cell {
+    Sand,
+    Rock,
+}
+
+pub type Cave = DynamicGrid<Cell>;
+
+fn parse_line(s: &str) -> IResult<&str, Vec<(isize, isize)>> {
+    separated_list1(
+        tag(" -> "),
+        separated_pair(map(i64, |x| x as isize), tag(","), map(i64, |y| y as isize)),
+    )(s)
+}
+
+pub fn gen(s: &str) -> Cave {
+    let mut h = DynamicGrid::new();
+    for l in s.lines() {
+        let points = parse_line(l).unwrap().1;
+        for a in points.windows(2) {
+            let a1 = a[0];
+            let a2 = a[1];
+            let diff_x = (a1.0 - a2.0).abs();
+            let diff_y = (a1.1 - a2.1).abs();
+            for dx in 0..=diff_x {
+                h.insert(Pos::new(dx + min(a1.0, a2.0), a1.1), Cell::Rock);
+            }
+            for dy in 0..=diff_y {
+                h.insert(Pos::new(a1.0, dy + min(a1.1, a2.1)), Cell::Rock);
+            }
+        }
+    }
+    h
+}
+
+pub fn p1(mut h: Cave) -> u32 {
+    let the_void = h.bounds().unwrap().max.y + 1;
+    let mut count = 0;
+    'outer: loop {
+        let mut pos = Pos::new(500, 0);
+        loop {
+            let new_y = pos.y + 1;
+            if new_y == the_void {
+                break 'outer;
+            }
+            let down = pos + Direction::S;
+            let down_left = pos + Direction::SW;
+            let down_right = pos + Direction::SE;
+            if !h.contains(down) {
+                pos = down;
+            } else if !h.contains(down_left) {
+                pos = down_left;
+            } else if !h.contains(down_right) {
+                pos = down_right;
+            } else {
+                // The grain has settled
+                h.insert(pos, Cell::Sand);
+                count += 1;
+                break;
+            }
+        }
+    }
+    count
+}
+
+pub fn p2(mut h: Cave) -> u32 {
+    let wall_y = h.bounds().unwrap().max.y + 2;
+    let mut count = 0;
+    loop {
+        let mut pos = Pos::new(500, 0);
+        loop {
+            let new_y = pos.y + 1;
+            // if we are on the wall, settle immediately
+            if new_y == wall_y {
+                h.insert(pos, Cell::Sand);
+                count += 1;
+                break;
+            }
+            // Otherwise simulate as usual
+            let down = pos + Direction::S;
+            let down_left = pos + Direction::SW;
+            let down_right = pos + Direction::SE;
+            if !h.contains(down) {
+                pos = down;
+            } else if !h.contains(down_left) {
+                pos = down_left;
+            } else if !h.contains(down_right) {
+                pos = down_right;
+            } else {
+                // The grain has settled
+                h.insert(pos, Cell::Sand);
+                count += 1;
+                // if we settled at the start position, return
+                if pos == Pos::new(500, 0) {
+                    return count;
+                }
+                break;
+            }
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Cave> {
+        Ok(gen(input))
+    }
+
+    fn part1(grid: &Cave) -> u32 {
+        p1(grid.clone())
+    }
+
+    fn part2(grid: &Cave) -> u32 {
+        p2(grid.clone())
+    }
+}
+
+#[cfg(test)]
+mod d14 {
+    use super::*;
+
+    #[test]
+    fn t1() {
+        let s = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+        let data = gen(s);
+        assert_eq!(p1(data), 24);
+    }
+}
@@ -0,0 +1,569 @@
+use crate::interval::IntervalSet;
+use crate::solution::{Params, Solution};
+use anyhow::{anyhow, Context, Result};
+use nom::{
+    bytes::complete::tag,
+    character::complete::i64,
+    combinator::map,
+    sequence::{pair, preceded, separated_pair},
+    Finish, IResult,
+};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+pub struct Day15;
+
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pos(pub i64, pub i64);
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl FromStr for Pos {
+    type Err = anyhow::Error;
+
+    /// Reads `x,y`.
+    fn from_str(s: &str) -> Result<Pos> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| anyhow!("Expected `x,y`, got `{s}`"))?;
+        let coord = |c: &str| {
+            c.trim()
+                .parse::<i64>()
+                .with_context(|| format!("Invalid coordinate `{c}` in `{s}`"))
+        };
+        Ok(Pos(coord(x)?, coord(y)?))
+    }
+}
+
+/// Reads `x=<x>, y=<y>`.
+fn pos(input: &str) -> IResult<&str, Pos> {
+    map(
+        separated_pair(
+            preceded(tag("x="), i64),
+            tag(", "),
+            preceded(tag("y="), i64),
+        ),
+        |(x, y)| Pos(x, y),
+    )(input)
+}
+
+/// Reads a sensor and its closest beacon, leaving whatever follows them.
+pub fn parse_line(input: &str) -> IResult<&str, (Pos, Pos)> {
+    pair(
+        preceded(tag("Sensor at "), pos),
+        preceded(tag(": closest beacon is at "), pos),
+    )(input)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Syntax,
+    /// Text after the beacon, only refused by [`gen_strict`].
+    Trailing(String),
+}
+
+/// A line of the report that is not a sensor, with its number from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub text: String,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ErrorKind::Syntax => write!(f, "unrecognized sensor `{}`", self.text),
+            ErrorKind::Trailing(rest) => {
+                write!(f, "unexpected `{rest}` after the beacon in `{}`", self.text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_sensors(input: &str, strict: bool) -> Result<Vec<(Pos, Pos)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(i, text)| {
+            let error = |kind| ParseError {
+                line: i + 1,
+                text: text.to_owned(),
+                kind,
+            };
+            match parse_line(text.trim_start()).finish() {
+                Ok((rest, _)) if strict && !rest.trim_end().is_empty() => {
+                    Err(error(ErrorKind::Trailing(rest.trim().to_owned())))
+                }
+                Ok((_, sensor)) => Ok(sensor),
+                Err(_) => Err(error(ErrorKind::Syntax)),
+            }
+        })
+        .collect()
+}
+
+/// Reads one sensor per line. Anything after the beacon is ignored, use
+/// [`gen_strict`] to refuse it.
+pub fn gen(input: &str) -> Result<Vec<(Pos, Pos)>, ParseError> {
+    parse_sensors(input, false)
+}
+
+/// Like [`gen`], but a line must end right after the beacon.
+pub fn gen_strict(input: &str) -> Result<Vec<(Pos, Pos)>, ParseError> {
+    parse_sensors(input, true)
+}
+
+pub fn distance(a: Pos, b: Pos) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// The columns of `row` that are within reach of some sensor, one interval per
+/// sensor merged together.
+pub fn row_cover(data: &[(Pos, Pos)], row: i64) -> IntervalSet {
+    data.iter()
+        .map(|&(s, b)| {
+            let reach = distance(s, b) - (s.1 - row).abs();
+            (s.0 - reach, s.0 + reach)
+        })
+        .collect()
+}
+
+/// Positions on `row` where no beacon can be: the covered columns, minus the
+/// beacons already known to sit there.
+pub fn p1(data: &[(Pos, Pos)], row: i64) -> u64 {
+    let cover = row_cover(data, row);
+    let beacons: BTreeSet<i64> = data
+        .iter()
+        .filter(|(_, b)| b.1 == row && cover.contains(b.0))
+        .map(|(_, b)| b.0)
+        .collect();
+    cover.len() - beacons.len() as u64
+}
+
+/// Whether `pos` is within reach of any sensor, so that no unknown beacon can
+/// be there.
+pub fn covered(data: &[(Pos, Pos)], pos: Pos) -> bool {
+    data.iter()
+        .any(|&(s, b)| distance(s, pos) <= distance(s, b))
+}
+
+pub fn tuning_frequency(pos: Pos) -> i64 {
+    pos.0 * 4_000_000 + pos.1
+}
+
+/// The columns of the diagonal `y = sign * x + c` that are within reach of
+/// some sensor.
+fn line_cover(data: &[(Pos, Pos)], sign: i64, c: i64) -> IntervalSet {
+    data.iter()
+        .map(|&(s, b)| {
+            // The distance to the sensor is `|x - s.0| + |x - mid|` along the line.
+            let mid = sign * (s.1 - c);
+            let reach = distance(s, b);
+            if (s.0 - mid).abs() > reach {
+                return (1, 0);
+            }
+            (
+                (s.0 + mid - reach + 1).div_euclid(2),
+                (s.0 + mid + reach).div_euclid(2),
+            )
+        })
+        .collect()
+}
+
+/// The cell of `0..=max` on both axes that no sensor reaches.
+///
+/// A neighbour of that cell is covered, so it sits one step outside the range
+/// of some sensor. Only the four sides of those diamonds need checking, a
+/// diagonal line at a time, instead of millions of rows. If no side is in the
+/// area at all, nothing in it is covered.
+pub fn p2(data: &[(Pos, Pos)], max: i64) -> Option<Pos> {
+    data.iter()
+        .flat_map(|&(s, b)| {
+            let outside = distance(s, b) + 1;
+            // Each side as `y = sign * x + c` for `x` in `lo..=hi`.
+            [
+                (1, s.1 - s.0 - outside, s.0, s.0 + outside),
+                (1, s.1 - s.0 + outside, s.0 - outside, s.0),
+                (-1, s.1 + s.0 - outside, s.0 - outside, s.0),
+                (-1, s.1 + s.0 + outside, s.0, s.0 + outside),
+            ]
+        })
+        .find_map(|(sign, c, lo, hi)| {
+            // Where the side is inside the search area.
+            let (lo, hi) = match sign {
+                1 => (lo.max(0).max(-c), hi.min(max).min(max - c)),
+                _ => (lo.max(0).max(c - max), hi.min(max).min(c)),
+            };
+            if lo > hi {
+                return None;
+            }
+            let (x, _) = *line_cover(data, sign, c).gaps(lo, hi).first()?;
+            Some(Pos(x, sign * x + c))
+        })
+        .or_else(|| (!covered(data, Pos(0, 0))).then_some(Pos(0, 0)))
+}
+
+/// What a cell of a drawing shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Sensor,
+    Beacon,
+    Covered,
+    Unknown,
+}
+
+impl Cell {
+    fn to_char(self) -> char {
+        match self {
+            Cell::Sensor => 'S',
+            Cell::Beacon => 'B',
+            Cell::Covered => '#',
+            Cell::Unknown => '.',
+        }
+    }
+
+    fn to_rgb(self) -> [u8; 3] {
+        match self {
+            Cell::Sensor => [220, 40, 40],
+            Cell::Beacon => [40, 90, 220],
+            Cell::Covered => [160, 160, 160],
+            Cell::Unknown => [0, 0, 0],
+        }
+    }
+}
+
+/// The window from `min` to `max` included, row by row, with one cell for
+/// every `scale` by `scale` block. A block shows a sensor or beacon inside it,
+/// otherwise whether its top left cell is covered.
+pub fn sample(data: &[(Pos, Pos)], min: Pos, max: Pos, scale: i64) -> Vec<Vec<Cell>> {
+    assert!(scale > 0, "Scale must be at least 1");
+    let block = |pos: Pos| {
+        let inside = (min.0..=max.0).contains(&pos.0) && (min.1..=max.1).contains(&pos.1);
+        inside.then(|| ((pos.0 - min.0) / scale, (pos.1 - min.1) / scale))
+    };
+    let mut marked = HashMap::new();
+    for &(_, b) in data {
+        marked.extend(block(b).map(|at| (at, Cell::Beacon)));
+    }
+    for &(s, _) in data {
+        marked.extend(block(s).map(|at| (at, Cell::Sensor)));
+    }
+
+    (min.1..=max.1)
+        .step_by(scale as usize)
+        .map(|y| {
+            let cover = row_cover(data, y);
+            (min.0..=max.0)
+                .step_by(scale as usize)
+                .map(|x| {
+                    let at = ((x - min.0) / scale, (y - min.1) / scale);
+                    match marked.get(&at) {
+                        Some(&cell) => cell,
+                        None if cover.contains(x) => Cell::Covered,
+                        None => Cell::Unknown,
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Draws the window like the puzzle does, with `S`, `B`, `#` for covered
+/// cells and `.` for the rest.
+pub fn render(data: &[(Pos, Pos)], min: Pos, max: Pos, scale: i64) -> String {
+    let mut s = String::new();
+    for row in sample(data, min, max, scale) {
+        s.extend(row.into_iter().map(Cell::to_char));
+        s.push('\n');
+    }
+    s
+}
+
+/// The same drawing as a binary PPM image, one pixel per cell: sensors in red,
+/// beacons in blue, covered cells in grey and the rest in black.
+pub fn render_ppm(data: &[(Pos, Pos)], min: Pos, max: Pos, scale: i64) -> Vec<u8> {
+    let rows = sample(data, min, max, scale);
+    let width = rows.first().map_or(0, Vec::len);
+    let mut image = format!("P6\n{width} {}\n255\n", rows.len()).into_bytes();
+    for cell in rows.into_iter().flatten() {
+        image.extend(cell.to_rgb());
+    }
+    image
+}
+
+/// How many cells from `min` to `max` included are within reach of a sensor,
+/// sensors and beacons included.
+pub fn covered_area(data: &[(Pos, Pos)], min: Pos, max: Pos) -> u64 {
+    let width = (max.0 - min.0 + 1).max(0) as u64;
+    (min.1..=max.1)
+        .map(|y| {
+            let gaps = row_cover(data, y).gaps(min.0, max.0);
+            width
+                - gaps
+                    .iter()
+                    .map(|(lo, hi)| (hi - lo + 1) as u64)
+                    .sum::<u64>()
+        })
+        .sum()
+}
+
+/// Every cell from `min` to `max` included that no sensor reaches, row by row.
+pub fn uncovered(data: &[(Pos, Pos)], min: Pos, max: Pos) -> Vec<Pos> {
+    (min.1..=max.1)
+        .flat_map(|y| {
+            row_cover(data, y)
+                .gaps(min.0, max.0)
+                .into_iter()
+                .flat_map(move |(lo, hi)| (lo..=hi).map(move |x| Pos(x, y)))
+        })
+        .collect()
+}
+
+/// Where the distress beacon is, shown as its tuning frequency, followed by
+/// the position itself with `{:#}`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Distress {
+    Found(Pos),
+    Missing,
+}
+
+impl fmt::Display for Distress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Distress::Found(pos) if f.alternate() => {
+                write!(f, "{} at {pos}", tuning_frequency(*pos))
+            }
+            Distress::Found(pos) => write!(f, "{}", tuning_frequency(*pos)),
+            Distress::Missing => write!(f, "missing, every cell in range is covered"),
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Vec<(Pos, Pos)>;
+    type Output1 = u64;
+    type Output2 = Distress;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(gen(input)?)
+    }
+
+    /// `strict=true` parses with [`gen_strict`], to refuse text after the beacons.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        match params.get("strict")? {
+            Some(true) => Ok(gen_strict(input)?),
+            _ => Self::parse(input),
+        }
+    }
+
+    /// `strict` is already used by [`parse_with`](Solution::parse_with).
+    fn configure(_input: &mut Self::Input, params: &Params) -> Result<()> {
+        params.only(&["strict"])
+    }
+
+    fn part1(data: &Self::Input) -> u64 {
+        p1(data, 2_000_000)
+    }
+
+    fn part2(data: &Self::Input) -> Distress {
+        match p2(data, 4_000_000) {
+            Some(pos) => Distress::Found(pos),
+            None => Distress::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod d15 {
+    use super::*;
+    use test_case::test_case;
+
+    const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+    #[test]
+    fn t1() {
+        let data = gen(INPUT).unwrap();
+        assert_eq!(p1(&data, 10), 26);
+    }
+
+    #[test]
+    fn negative() {
+        let data = gen_strict("Sensor at x=-3, y=-14: closest beacon is at x=0, y=-2\n").unwrap();
+        assert_eq!(data, [(Pos(-3, -14), Pos(0, -2))]);
+    }
+
+    #[test_case("Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16\n", 2, ErrorKind::Syntax; "missing beacon")]
+    #[test_case("Sensor at x=2, y=1.5: closest beacon is at x=-2, y=15", 1, ErrorKind::Syntax; "not an integer")]
+    #[test_case("\nSensor at x=2, y=18: closest beacon is at x=- 2, y=15", 2, ErrorKind::Syntax; "broken sign")]
+    fn broken(input: &str, line: usize, kind: ErrorKind) {
+        let error = gen(input).unwrap_err();
+        assert_eq!((error.line, error.kind), (line, kind));
+        assert_eq!(gen_strict(input).unwrap_err().line, line);
+    }
+
+    #[test]
+    fn trailing() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15 (far away)";
+        assert_eq!(gen(input).unwrap(), [(Pos(2, 18), Pos(-2, 15))]);
+        let error = gen_strict(input).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Trailing(String::from("(far away)")));
+        assert_eq!(
+            error.to_string(),
+            format!("line 1: unexpected `(far away)` after the beacon in `{input}`")
+        );
+        assert!(gen_strict("Sensor at x=1, y=2: closest beacon is at x=3, y=4\r\n").is_ok());
+        assert!(gen_strict(&format!("{INPUT}  \n\n")).is_ok());
+    }
+
+    #[test]
+    fn strict_setting() {
+        let strict = Params::parse(&["strict=true"]).unwrap();
+        let lenient = Params::parse(&["strict=false"]).unwrap();
+        let input = format!("{INPUT}\n{INPUT} and more");
+        assert_eq!(Day15::parse_with(&input, &lenient).unwrap().len(), 28);
+        let error = Day15::parse_with(&input, &strict).unwrap_err();
+        assert_eq!(error.downcast_ref::<ParseError>().map(|e| e.line), Some(28));
+
+        let mut input = Day15::parse_with(INPUT, &strict).unwrap();
+        Day15::configure(&mut input, &strict).unwrap();
+        assert!(Day15::parse_with(INPUT, &Params::parse(&["strict=yes"]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn cover() {
+        let data = gen(INPUT).unwrap();
+        assert_eq!(row_cover(&data, 10).iter().collect::<Vec<_>>(), [(-2, 24)]);
+        // The sensor at (2, 0) reaches exactly up to row -10 and no further.
+        assert_eq!(row_cover(&data, -10).iter().collect::<Vec<_>>(), [(2, 2)]);
+        assert!(row_cover(&data, -11).is_empty());
+    }
+
+    #[test]
+    fn t2() {
+        let data = gen(INPUT).unwrap();
+        assert_eq!(p2(&data, 20), Some(Pos(14, 11)));
+        assert_eq!(Distress::Found(Pos(14, 11)).to_string(), "56000011");
+        assert_eq!(
+            format!("{:#}", Distress::Found(Pos(14, 11))),
+            "56000011 at (14, 11)"
+        );
+    }
+
+    #[test]
+    fn draw() {
+        let data = gen(INPUT).unwrap();
+        // Only the sensor at (8, 7), as in the puzzle's diagram.
+        let drawing = render(&data[6..7], Pos(-2, -2), Pos(25, 22), 1);
+        let lines: Vec<_> = drawing.lines().collect();
+        assert_eq!(lines.len(), 25);
+        assert_eq!(lines[0], "..........#.................");
+        assert_eq!(lines[9], ".#########S#########........");
+        assert_eq!(lines[12], "....B############...........");
+        assert_eq!(lines[24], "............................");
+
+        let drawing = render(&data, Pos(10, 9), Pos(16, 12), 1);
+        assert_eq!(drawing, "#######\n#######\n####.##\n#######\n");
+        let scaled = render(&data, Pos(-2, -2), Pos(25, 22), 4);
+        assert_eq!(scaled.lines().next(), Some("#S##.S#"));
+        assert_eq!(scaled.lines().count(), 7);
+    }
+
+    #[test]
+    fn ppm() {
+        let data = gen(INPUT).unwrap();
+        let image = render_ppm(&data, Pos(0, 0), Pos(20, 20), 2);
+        let header = b"P6\n11 11\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(image.len(), header.len() + 11 * 11 * 3);
+    }
+
+    #[test]
+    fn stats() {
+        let data = gen(INPUT).unwrap();
+        assert_eq!(uncovered(&data, Pos(0, 0), Pos(20, 20)), [Pos(14, 11)]);
+        assert_eq!(covered_area(&data, Pos(0, 0), Pos(20, 20)), 21 * 21 - 1);
+        assert_eq!(covered_area(&data, Pos(-2, 10), Pos(24, 10)), 27);
+        assert_eq!(covered_area(&data, Pos(10, 10), Pos(0, 10)), 0);
+        assert!(uncovered(&data, Pos(10, 10), Pos(0, 10)).is_empty());
+        assert_eq!(uncovered(&data, Pos(-5, 10), Pos(-3, 10)).len(), 3);
+        assert_eq!("-3, 14".parse::<Pos>().unwrap(), Pos(-3, 14));
+        assert!("3".parse::<Pos>().is_err());
+    }
+
+    #[test]
+    fn missing() {
+        let data = gen(INPUT).unwrap();
+        // The only gap of the sample is out of this smaller range.
+        assert_eq!(p2(&data, 10), None);
+        assert!(covered(&data, Pos(13, 11)) && !covered(&data, Pos(14, 11)));
+    }
+
+    #[test_case(0, 0; "corner")]
+    #[test_case(0, 7; "left edge")]
+    #[test_case(5, 10; "bottom edge")]
+    #[test_case(4, 6; "inside")]
+    fn hidden(x: i64, y: i64) {
+        // Four diagonal sensors, each one step short of the hidden cell, cover
+        // all the rest of the area.
+        let reach = [x, 10 - x, y, 10 - y].into_iter().max().unwrap();
+        let data: Vec<_> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+            .into_iter()
+            .map(|(dx, dy)| {
+                let s = Pos(x + dx * reach, y + dy * reach);
+                (s, Pos(s.0 + 2 * reach - 1, s.1))
+            })
+            .collect();
+        assert_eq!(p2(&data, 10), Some(Pos(x, y)));
+    }
+
+    #[test]
+    fn shared_constant() {
+        // 16 is the constant of both a rising and a falling line here, the
+        // beacon is on the right edge of the falling one.
+        let data = [
+            (Pos(-3, 14), Pos(12, 17)),
+            (Pos(-3, 3), Pos(-1, 12)),
+            (Pos(-4, -4), Pos(2, 12)),
+            (Pos(7, -1), Pos(7, 8)),
+            (Pos(2, 10), Pos(4, -2)),
+            (Pos(14, 16), Pos(8, 9)),
+        ];
+        assert_eq!(p2(&data, 12), Some(Pos(12, 4)));
+    }
+
+    #[test]
+    fn parallel_sides() {
+        // The sensors right next to the beacon share one side, the cells
+        // along it are covered by sensors two steps away.
+        let data = [
+            (Pos(-4, 5), Pos(-3, 0)),
+            (Pos(3, 5), Pos(-1, 5)),
+            (Pos(-4, 7), Pos(-3, 0)),
+            (Pos(7, -1), Pos(3, -4)),
+            (Pos(0, 0), Pos(-1, -1)),
+            (Pos(8, -2), Pos(7, 5)),
+        ];
+        assert_eq!(p2(&data, 5), Some(Pos(1, 2)));
+    }
+}
@@ -0,0 +1,383 @@
+use crate::solution::{Params, Solution};
+use anyhow::{anyhow, Result};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, u32},
+    combinator::{all_consuming, map},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    Finish, IResult, Parser,
+};
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct Day16;
+
+const TIME_LIMIT: u32 = 30;
+const TIME_LIMIT_P2: u32 = 26;
+
+#[derive(Debug)]
+pub struct ValveInfo {
+    pub flow: u32,
+    pub neighbors: Vec<String>,
+}
+
+fn parse_line(input: &str) -> IResult<&str, (String, u32, Vec<String>)> {
+    tuple((parse_valve_name, parse_flow_rate, parse_neighbors))(input)
+}
+
+fn parse_valve_name(input: &str) -> IResult<&str, String> {
+    preceded(tag("Valve "), map(alpha1, String::from))(input)
+}
+
+fn parse_flow_rate(input: &str) -> IResult<&str, u32> {
+    preceded(tag(" has flow rate="), u32)(input)
+}
+
+fn parse_neighbors(input: &str) -> IResult<&str, Vec<String>> {
+    preceded(
+        tag("; tunnels lead to valves ").or(tag("; tunnel leads to valve ")),
+        separated_list1(tag(", "), map(alpha1, String::from)),
+    )(input)
+}
+
+/// A line of the scan that is not a valve, with its number from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub text: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: unrecognized valve `{}`", self.line, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn gen(input: &str) -> Result<HashMap<String, ValveInfo>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(
+            |(i, text)| match all_consuming(parse_line)(text.trim()).finish() {
+                Ok((_, (name, flow, neighbors))) => Ok((name, ValveInfo { flow, neighbors })),
+                Err(_) => Err(ParseError {
+                    line: i + 1,
+                    text: text.to_owned(),
+                }),
+            },
+        )
+        .collect()
+}
+
+/// The valves worth opening, plus the start, with the minutes it takes to
+/// walk between any two of them. Valves are numbered so that a set of them
+/// fits in the bits of a `u64`.
+#[derive(Clone, Debug)]
+pub struct Valves {
+    pub names: Vec<String>,
+    pub flow: Vec<u32>,
+    pub dist: Vec<Vec<u32>>,
+    pub start: usize,
+}
+
+impl Valves {
+    pub const START: &'static str = "AA";
+
+    pub fn new(graph: &HashMap<String, ValveInfo>) -> Result<Valves> {
+        let mut all: Vec<&String> = graph.keys().collect();
+        all.sort();
+        let index: HashMap<&str, usize> = all
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+        if !index.contains_key(Valves::START) {
+            return Err(anyhow!("There is no valve {}", Valves::START));
+        }
+
+        // Floyd–Warshall over every valve, then only the useful ones are kept.
+        const FAR: u32 = u32::MAX / 2;
+        let n = all.len();
+        let mut dist = vec![vec![FAR; n]; n];
+        for (i, name) in all.iter().enumerate() {
+            dist[i][i] = 0;
+            for neighbor in &graph[*name].neighbors {
+                let &j = index
+                    .get(neighbor.as_str())
+                    .ok_or_else(|| anyhow!("Valve {name} leads to unknown valve {neighbor}"))?;
+                dist[i][j] = 1;
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+                }
+            }
+        }
+
+        let kept: Vec<usize> = (0..n)
+            .filter(|&i| graph[all[i]].flow > 0 || all[i] == Valves::START)
+            .collect();
+        if kept.len() > u64::BITS as usize {
+            return Err(anyhow!(
+                "{} valves have a flow, at most {} are supported",
+                kept.len() - 1,
+                u64::BITS - 1
+            ));
+        }
+        Ok(Valves {
+            names: kept.iter().map(|&i| all[i].clone()).collect(),
+            flow: kept.iter().map(|&i| graph[all[i]].flow).collect(),
+            dist: kept
+                .iter()
+                .map(|&i| kept.iter().map(|&j| dist[i][j]).collect())
+                .collect(),
+            start: kept.iter().position(|&i| all[i] == Valves::START).unwrap(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The valves that have a flow and are not in `opened`.
+    fn closed(&self, opened: u64) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(move |&v| self.flow[v] > 0 && opened & (1 << v) == 0)
+    }
+
+    /// Every way `agent` can walk to a closed valve and open it with time to
+    /// spare: where it ends up, and how much that valve will release.
+    fn moves(&self, (pos, time): Agent, opened: u64) -> impl Iterator<Item = (Agent, u32)> + '_ {
+        self.closed(opened).filter_map(move |v| {
+            let left = time.checked_sub(self.dist[pos][v] + 1)?;
+            (left > 0).then_some(((v, left), left * self.flow[v]))
+        })
+    }
+}
+
+/// Where someone is and how many minutes they have left.
+type Agent = (usize, u32);
+
+/// Depth first search for the best order to open valves in alone, dropping
+/// the branches that could not beat the best so far even if every closed
+/// valve was reached straight from where the agent stands.
+fn search(valves: &Valves, agent: Agent, opened: u64, released: u32, best: &mut u32) {
+    *best = released.max(*best);
+    let (pos, time) = agent;
+    let bound: u32 = valves
+        .closed(opened)
+        .map(|v| time.saturating_sub(valves.dist[pos][v] + 1) * valves.flow[v])
+        .sum();
+    if released + bound <= *best {
+        return;
+    }
+    for (next, gain) in valves.moves(agent, opened) {
+        search(valves, next, opened | 1 << next.0, released + gain, best);
+    }
+}
+
+/// The most pressure one agent starting at `AA` can release in `minutes`.
+pub fn best_route(valves: &Valves, minutes: u32) -> u32 {
+    let mut best = 0;
+    search(valves, (valves.start, minutes), 0, 0, &mut best);
+    best
+}
+
+/// For every set of valves one agent can open in `minutes`, the most pressure
+/// it can release by opening exactly those, the empty set included.
+pub fn best_per_subset(valves: &Valves, minutes: u32) -> HashMap<u64, u32> {
+    let mut best = HashMap::new();
+    let mut stack = vec![((valves.start, minutes), 0, 0)];
+    while let Some((agent, opened, released)) = stack.pop() {
+        let entry = best.entry(opened).or_insert(0);
+        *entry = released.max(*entry);
+        for (next, gain) in valves.moves(agent, opened) {
+            stack.push((next, opened | 1 << next.0, released + gain));
+        }
+    }
+    best
+}
+
+/// The best total of `agents` pairwise disjoint sets from `subsets`, which is
+/// sorted from the most released down.
+fn combine(subsets: &[(u64, u32)], agents: usize, used: u64, released: u32, best: &mut u32) {
+    *best = released.max(*best);
+    if agents == 0 {
+        return;
+    }
+    for (i, &(set, gain)) in subsets.iter().enumerate() {
+        // Nothing after this set releases more than it does.
+        if released.saturating_add(gain.saturating_mul(agents as u32)) <= *best {
+            break;
+        }
+        if set & used == 0 {
+            combine(
+                &subsets[i + 1..],
+                agents - 1,
+                used | set,
+                released + gain,
+                best,
+            );
+        }
+    }
+}
+
+/// The most pressure `agents` starting together at `AA` can release in
+/// `minutes`. They never need to open the same valve, so each one gets its
+/// own set of valves, and the best release of every set only has to be found
+/// once. Agents past one per valve have nothing left to open.
+pub fn most_pressure(valves: &Valves, minutes: u32, agents: usize) -> u32 {
+    let agents = agents.min(valves.len());
+    let mut subsets: Vec<(u64, u32)> = best_per_subset(valves, minutes).into_iter().collect();
+    subsets.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let mut best = 0;
+    combine(&subsets, agents, 0, 0, &mut best);
+    best
+}
+
+pub fn p1(valves: &Valves) -> u32 {
+    best_route(valves, TIME_LIMIT)
+}
+
+/// Part 2 with `elephants` helping, one in the puzzle.
+pub fn p2(valves: &Valves, elephants: usize) -> u32 {
+    most_pressure(valves, TIME_LIMIT_P2, elephants.saturating_add(1))
+}
+
+impl Solution for Day16 {
+    type Input = (Valves, usize);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((Valves::new(&gen(input)?)?, 1))
+    }
+
+    /// Accepts `elephants`, how many of them help in part 2.
+    fn configure((_, elephants): &mut Self::Input, params: &Params) -> Result<()> {
+        params.only(&["elephants"])?;
+        if let Some(n) = params.get("elephants")? {
+            *elephants = n;
+        }
+        Ok(())
+    }
+
+    fn part1((valves, _): &Self::Input) -> u32 {
+        p1(valves)
+    }
+
+    fn part2((valves, elephants): &Self::Input) -> u32 {
+        p2(valves, *elephants)
+    }
+}
+
+#[cfg(test)]
+mod d16 {
+    use super::*;
+    use test_case::test_case;
+
+    const S: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn t1() {
+        let valves = Valves::new(&gen(S).unwrap()).unwrap();
+        assert_eq!(p1(&valves), 1651)
+    }
+    #[test]
+    fn t2() {
+        let valves = Valves::new(&gen(S).unwrap()).unwrap();
+        assert_eq!(p2(&valves, 1), 1707)
+    }
+
+    #[test]
+    fn compressed() {
+        let valves = Valves::new(&gen(S).unwrap()).unwrap();
+        assert_eq!(valves.names, ["AA", "BB", "CC", "DD", "EE", "HH", "JJ"]);
+        assert_eq!(valves.flow, [0, 13, 2, 20, 3, 22, 21]);
+        assert_eq!(valves.start, 0);
+        let id = |name| valves.names.iter().position(|n| n == name).unwrap();
+        assert_eq!(valves.dist[id("AA")][id("HH")], 5);
+        assert_eq!(valves.dist[id("JJ")][id("HH")], 7);
+        assert_eq!(valves.dist[id("CC")][id("CC")], 0);
+    }
+
+    #[test]
+    fn short_time() {
+        let valves = Valves::new(&gen(S).unwrap()).unwrap();
+        assert_eq!(most_pressure(&valves, 0, 1), 0);
+        // Walking to DD and opening it takes the first two minutes.
+        assert_eq!(most_pressure(&valves, 2, 1), 0);
+        assert_eq!(most_pressure(&valves, 3, 1), 20);
+        assert_eq!(best_route(&valves, 3), 20);
+        assert_eq!(most_pressure(&valves, 3, 2), 20 + 13);
+    }
+
+    #[test]
+    fn subsets() {
+        let valves = Valves::new(&gen(S).unwrap()).unwrap();
+        let best = best_per_subset(&valves, TIME_LIMIT_P2);
+        let set = |names: &[&str]| {
+            names.iter().fold(0, |set, name| {
+                set | 1 << valves.names.iter().position(|n| n == name).unwrap()
+            })
+        };
+        // The two halves of the puzzle's answer.
+        assert_eq!(best[&set(&["JJ", "BB", "CC"])], 23 * 21 + 19 * 13 + 17 * 2);
+        assert_eq!(best[&set(&["DD", "HH", "EE"])], 24 * 20 + 19 * 22 + 15 * 3);
+        assert_eq!(best[&0], 0);
+        assert!(best.keys().all(|set| set & 1 << valves.start == 0));
+    }
+
+    #[test]
+    fn elephants() {
+        let valves = Valves::new(&gen(S).unwrap()).unwrap();
+        assert_eq!(p2(&valves, 0), best_route(&valves, TIME_LIMIT_P2));
+        // With six valves to open, more than two helpers can't do better
+        // than one valve each.
+        let with_two = p2(&valves, 2);
+        assert!(with_two > 1707);
+        assert_eq!(p2(&valves, 5), p2(&valves, 9));
+        assert_eq!(p2(&valves, 1 << 32), p2(&valves, 9));
+        assert_eq!(p2(&valves, usize::MAX), p2(&valves, 9));
+
+        let mut input = Day16::parse(S).unwrap();
+        Day16::configure(&mut input, &Params::parse(&["elephants=2"]).unwrap()).unwrap();
+        assert_eq!(Day16::part2(&input), with_two);
+        assert!(Day16::configure(&mut input, &Params::parse(&["elephants=-1"]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn invalid_graph() {
+        let graph = gen("Valve BB has flow rate=1; tunnel leads to valve BB").unwrap();
+        assert!(Valves::new(&graph).is_err());
+        let graph = gen("Valve AA has flow rate=0; tunnel leads to valve ZZ").unwrap();
+        assert!(Valves::new(&graph).is_err());
+    }
+
+    #[test_case("Valve AA has flow rate=x; tunnel leads to valve BB", 1; "bad flow")]
+    #[test_case("Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB", 2; "cut short")]
+    #[test_case("\nValve AA has flow rate=0; tunnels lead to valves BB,CC", 2; "no space")]
+    fn broken(input: &str, line: usize) {
+        let error = gen(input).unwrap_err();
+        assert_eq!(error.line, line);
+        assert!(error.to_string().starts_with(&format!("line {line}: ")));
+    }
+}
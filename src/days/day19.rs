@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
+use crate::solution::Solution;
 use anyhow::{anyhow, Context, Result};
 use derivative::Derivative;
 use nom::{
@@ -17,8 +18,9 @@ use nom::{
 use nom::{Finish, IResult};
 use regex::Regex;
 use std::str::FromStr;
-use std::time::Instant;
-use std::{collections::HashMap, default, fs, hash};
+use std::{collections::HashMap, default, hash};
+
+pub struct Day19;
 
 #[derive(Debug, PartialEq, Eq)]
 enum Resource {
@@ -45,7 +47,7 @@ impl FromStr for Resource {
 type Cost = (u8, Resource);
 
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     id: u8,
     ore_robot_cost: u8,
     clay_robot_cost: u8,
@@ -53,7 +55,7 @@ struct Blueprint {
     geode_robot_cost: (u8, u8),
 }
 
-fn gen(input: &str) -> Result<Vec<Blueprint>> {
+pub fn gen(input: &str) -> Result<Vec<Blueprint>> {
     input
         .lines()
         .map(|l| Blueprint::new(l.trim()))
@@ -212,7 +214,7 @@ impl Status {
 
 use rayon::prelude::*;
 
-fn p1(input: &[Blueprint]) -> u32 {
+pub fn p1(input: &[Blueprint]) -> u32 {
     input
         .par_iter()
         .map(|bp| {
@@ -369,7 +371,7 @@ fn best_geodes(
     result
 }
 
-fn p2(input: &[Blueprint]) -> u32 {
+pub fn p2(input: &[Blueprint]) -> u32 {
     input[..3]
         .par_iter()
         .map(|bp| {
@@ -388,27 +390,22 @@ fn p2(input: &[Blueprint]) -> u32 {
         .product()
 }
 
-fn main() -> Result<()> {
-    let path = "inputs/19.txt";
-    let input = fs::read_to_string(path).unwrap();
-
-    let in1 = Instant::now();
-    let blueprints = gen(&input)?;
-    let in0 = Instant::now();
-    println!("Input parsed in: {:?}", in0.duration_since(in1));
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Output1 = u32;
+    type Output2 = u32;
 
-    let i11 = Instant::now();
-    let res1 = p1(&blueprints);
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+    fn parse(input: &str) -> Result<Vec<Blueprint>> {
+        gen(input)
+    }
 
-    println!("-----");
+    fn part1(blueprints: &Vec<Blueprint>) -> u32 {
+        p1(blueprints)
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2(&blueprints);
-    let i22 = Instant::now();
-    println!("gold: {:?}\ntime: {:?}", res2, i22.duration_since(i21));
-    Ok(())
+    fn part2(blueprints: &Vec<Blueprint>) -> u32 {
+        p2(blueprints)
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use std::str::FromStr;
 
-const INPUT: &str = include_str!("../../inputs/2.txt");
+pub struct Day2;
 
 enum Sign {
     Rock,
@@ -35,7 +37,7 @@ fn points(yours: &Sign, others: &Sign) -> u64 {
     }
 }
 
-fn p1(input: &str) -> u64 {
+pub fn p1(input: &str) -> u64 {
     input
         .lines()
         .map(|l| {
@@ -81,7 +83,7 @@ fn points_2(others: &Sign, end: &End) -> u64 {
     }
 }
 
-fn p2(input: &str) -> u64 {
+pub fn p2(input: &str) -> u64 {
     input
         .lines()
         .map(|l| {
@@ -93,9 +95,22 @@ fn p2(input: &str) -> u64 {
         .sum()
 }
 
-fn main() {
-    println!("{}", p1(INPUT));
-    println!("{}", p2(INPUT));
+impl Solution for Day2 {
+    type Input = String;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> u64 {
+        p1(input)
+    }
+
+    fn part2(input: &String) -> u64 {
+        p2(input)
+    }
 }
 
 #[cfg(test)]
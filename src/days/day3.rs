@@ -1,7 +1,8 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
-use std::time::Instant;
 
-const INPUT: &str = include_str!("../../inputs/3.txt");
+pub struct Day3;
 
 fn priority(c: char) -> u64 {
     let mut buf = [0; 1];
@@ -16,7 +17,7 @@ fn priority(c: char) -> u64 {
     panic!("Unexpected char {c}");
 }
 
-fn p1(input: &str) -> u64 {
+pub fn p1(input: &str) -> u64 {
     input
         .lines()
         .map(|l| {
@@ -27,7 +28,7 @@ fn p1(input: &str) -> u64 {
         .sum()
 }
 
-fn p2(input: &str) -> u64 {
+pub fn p2(input: &str) -> u64 {
     let mut will_sum = vec![];
     for (first, second, third) in input.lines().tuples() {
         let mut letters: Vec<_> = first.chars().collect();
@@ -37,18 +38,22 @@ fn p2(input: &str) -> u64 {
     will_sum.iter().sum()
 }
 
-fn main() {
-    let i11 = Instant::now();
-    let res1 = p1(INPUT);
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+impl Solution for Day3 {
+    type Input = String;
+    type Output1 = u64;
+    type Output2 = u64;
 
-    println!("-----");
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> u64 {
+        p1(input)
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2(INPUT);
-    let i22 = Instant::now();
-    println!("gold: {:?}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(input: &String) -> u64 {
+        p2(input)
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-const INPUT: &str = include_str!("../../inputs/4.txt");
+use crate::solution::Solution;
+use anyhow::Result;
+
+pub struct Day4;
 
 fn ranges(l: &str) -> (u64, u64, u64, u64) {
     let (r1, r2) = l.split_once(',').unwrap();
@@ -11,7 +14,7 @@ fn ranges(l: &str) -> (u64, u64, u64, u64) {
     (s11, s12, s21, s22)
 }
 
-fn p1(input: &str) -> u64 {
+pub fn p1(input: &str) -> u64 {
     input
         .lines()
         .map(|l| {
@@ -21,7 +24,7 @@ fn p1(input: &str) -> u64 {
         .sum()
 }
 
-fn p2(input: &str) -> u64 {
+pub fn p2(input: &str) -> u64 {
     input
         .lines()
         .map(|l| {
@@ -35,9 +38,22 @@ fn p2(input: &str) -> u64 {
         .sum::<u64>()
 }
 
-fn main() {
-    println!("{}", p1(INPUT));
-    println!("{}", p2(INPUT));
+impl Solution for Day4 {
+    type Input = String;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> u64 {
+        p1(input)
+    }
+
+    fn part2(input: &String) -> u64 {
+        p2(input)
+    }
 }
 
 #[cfg(test)]
@@ -1,36 +1,24 @@
-use once_cell::sync::Lazy;
+use crate::solution::Solution;
+use anyhow::Result;
 use regex::Regex;
-use std::fs;
-use std::time::Instant;
 
-static INPUT: Lazy<Data> = Lazy::new(|| {
-    // let path = "bigboys/5/bigboy.txt";
-    let path = "inputs/5.txt";
-    let input = fs::read_to_string(path).unwrap();
-
-    let in1 = Instant::now();
-    let res = Data::new(&input);
-    let in0 = Instant::now();
-    println!("Input parsed in: {:?}", in0.duration_since(in1));
-
-    res
-});
+pub struct Day5;
 
 #[derive(Debug, Clone)]
-struct Instruction {
+pub struct Instruction {
     num: usize,
     from: usize,
     to: usize,
 }
 
 #[derive(Debug, Clone)]
-struct Data {
+pub struct Data {
     crates: Vec<Vec<char>>,
     instructions: Vec<Instruction>,
 }
 
 impl Data {
-    fn new(input: &str) -> Data {
+    pub fn new(input: &str) -> Data {
         let lines: Vec<_> = input.lines().collect();
         let stack_line = lines
             .iter()
@@ -77,7 +65,7 @@ impl Data {
     }
 }
 
-fn p1(mut data: Data) -> String {
+pub fn p1(mut data: Data) -> String {
     for instruction in data.instructions {
         for _ in 0..instruction.num {
             let to_push = data.crates[instruction.from].pop().unwrap();
@@ -87,7 +75,7 @@ fn p1(mut data: Data) -> String {
     data.crates.iter_mut().map(|c| c.pop().unwrap()).collect()
 }
 
-fn p2(mut data: Data) -> String {
+pub fn p2(mut data: Data) -> String {
     for inst in data.instructions {
         let split = data.crates[inst.from].len() - inst.num;
         let mut moving = data.crates[inst.from].split_off(split);
@@ -96,25 +84,22 @@ fn p2(mut data: Data) -> String {
     data.crates.iter_mut().map(|c| c.pop().unwrap()).collect()
 }
 
-fn main() {
-    let data = Lazy::force(&INPUT);
+impl Solution for Day5 {
+    type Input = Data;
+    type Output1 = String;
+    type Output2 = String;
 
-    let data1 = data.clone();
-    let data2 = data.clone();
+    fn parse(input: &str) -> Result<Data> {
+        Ok(Data::new(input))
+    }
 
-    let i11 = Instant::now();
-    let res1 = p1(data1);
-    let i12 = Instant::now();
-    println!(
-        "silver: {:?}\ntime: {:?}\n--",
-        res1,
-        i12.duration_since(i11)
-    );
+    fn part1(data: &Data) -> String {
+        p1(data.clone())
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2(data2);
-    let i22 = Instant::now();
-    println!("gold: {:?}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(data: &Data) -> String {
+        p2(data.clone())
+    }
 }
 
 #[cfg(test)]
@@ -1,15 +1,10 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
-use once_cell::sync::Lazy;
-use std::fs;
-use std::time::Instant;
 
-static INPUT: Lazy<String> = Lazy::new(|| {
-    // let path = "bigboys/6/bigboy.txt";
-    let path = "inputs/6.txt";
-    fs::read_to_string(path).unwrap()
-});
+pub struct Day6;
 
-fn solve(input: &str, distinct_chars: usize) -> usize {
+pub fn solve(input: &str, distinct_chars: usize) -> usize {
     input
         .as_bytes()
         .windows(distinct_chars)
@@ -23,28 +18,30 @@ fn solve(input: &str, distinct_chars: usize) -> usize {
         + distinct_chars
 }
 
-fn p1(input: &str) -> usize {
+pub fn p1(input: &str) -> usize {
     solve(input, 4)
 }
 
-fn p2(input: &str) -> usize {
+pub fn p2(input: &str) -> usize {
     solve(input, 14)
 }
 
-fn main() {
-    let data = Lazy::force(&INPUT);
+impl Solution for Day6 {
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let i11 = Instant::now();
-    let res1 = p1(data);
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
 
-    println!("---");
+    fn part1(input: &String) -> usize {
+        p1(input)
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2(data);
-    let i22 = Instant::now();
-    println!("gold: {:?}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(input: &String) -> usize {
+        p2(input)
+    }
 }
 
 #[cfg(test)]
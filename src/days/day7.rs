@@ -0,0 +1,701 @@
+use crate::solution::{Params, Solution};
+use anyhow::{anyhow, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, u64},
+    combinator::{all_consuming, map, opt, recognize, value},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+    Finish, IResult,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Write};
+
+pub struct Day7;
+
+/// The size of the filesystem and how much of it has to be free for the update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Disk {
+    pub total: u64,
+    pub needed: u64,
+}
+
+impl Default for Disk {
+    fn default() -> Self {
+        Disk {
+            total: 70_000_000,
+            needed: 30_000_000,
+        }
+    }
+}
+
+/// One line of the transcript.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(u64, &'a str),
+}
+
+fn parse_name(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| !c.is_whitespace() && c != '/')(input)
+}
+
+/// The target of `cd`: `/`, or names separated by `/`, where a name can also
+/// be `..`.
+fn parse_path(input: &str) -> IResult<&str, &str> {
+    alt((
+        recognize(tuple((
+            opt(char('/')),
+            separated_list1(char('/'), parse_name),
+            opt(char('/')),
+        ))),
+        tag("/"),
+    ))(input)
+}
+
+pub fn parse_line(input: &str) -> IResult<&str, Line<'_>> {
+    alt((
+        map(preceded(tag("$ cd "), parse_path), Line::Cd),
+        value(Line::Ls, tag("$ ls")),
+        map(preceded(tag("dir "), parse_name), Line::Dir),
+        map(
+            separated_pair(u64, char(' '), parse_name),
+            |(size, name)| Line::File(size, name),
+        ),
+    ))(input)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Syntax(String),
+    AboveRoot,
+    OutputWithoutLs,
+    /// The path was already listed as a different file or directory.
+    Conflict(String),
+    /// `cd` into a directory its parent's listing did not have.
+    NoSuchDir(String),
+}
+
+/// Why a transcript could not be replayed, with the 1-based line it happened on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ErrorKind::Syntax(text) => write!(f, "unrecognized line `{text}`"),
+            ErrorKind::AboveRoot => write!(f, "`cd ..` above the root"),
+            ErrorKind::OutputWithoutLs => write!(f, "listing output without `$ ls`"),
+            ErrorKind::Conflict(path) => write!(f, "{path} was listed differently before"),
+            ErrorKind::NoSuchDir(path) => write!(f, "{path} is not in its parent's listing"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Index of a node in [`Fs`].
+pub type NodeId = usize;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Dir(BTreeMap<String, NodeId>),
+    File(u64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
+    /// The file size, or everything below a directory.
+    total: u64,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir(_))
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.total
+    }
+}
+
+/// The filesystem rebuilt from a transcript. Nodes live in one `Vec` and refer to
+/// each other by index; a parent always comes before its children.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fs {
+    nodes: Vec<Node>,
+}
+
+impl Default for Fs {
+    fn default() -> Self {
+        Fs {
+            nodes: vec![Node {
+                name: String::from("/"),
+                parent: None,
+                kind: Kind::Dir(BTreeMap::new()),
+                total: 0,
+            }],
+        }
+    }
+}
+
+impl Fs {
+    pub const ROOT: NodeId = 0;
+
+    /// Replays a transcript. `cd` into a directory whose parent was never listed
+    /// creates it, listing a directory again only adds what is new.
+    pub fn parse(input: &str) -> Result<Fs, ParseError> {
+        let mut fs = Fs::default();
+        let mut cwd = Fs::ROOT;
+        let mut listing = false;
+        let mut listed = HashSet::new();
+        for (i, text) in input.lines().enumerate() {
+            let error = |kind| ParseError { line: i + 1, kind };
+            let line = match all_consuming(parse_line)(text).finish() {
+                Ok((_, line)) => line,
+                Err(_) => return Err(error(ErrorKind::Syntax(text.to_owned()))),
+            };
+            match line {
+                Line::Cd(path) => {
+                    cwd = fs.cd(cwd, path, &listed).map_err(error)?;
+                    listing = false;
+                }
+                Line::Ls => {
+                    listing = true;
+                    listed.insert(cwd);
+                }
+                Line::Dir(_) | Line::File(..) if !listing => {
+                    return Err(error(ErrorKind::OutputWithoutLs));
+                }
+                Line::Dir(name) => {
+                    fs.mkdir(cwd, name).map_err(error)?;
+                }
+                Line::File(size, name) => {
+                    fs.touch(cwd, name, size).map_err(error)?;
+                }
+            }
+        }
+        fs.sum_sizes();
+        Ok(fs)
+    }
+
+    /// Follows `path` from `cwd`, or from the root if it starts with `/`. Only
+    /// directories that are not in `listed` can get new entries on the way.
+    fn cd(
+        &mut self,
+        cwd: NodeId,
+        path: &str,
+        listed: &HashSet<NodeId>,
+    ) -> Result<NodeId, ErrorKind> {
+        let start = if path.starts_with('/') { Fs::ROOT } else { cwd };
+        path.split('/')
+            .filter(|part| !part.is_empty() && *part != ".")
+            .try_fold(start, |dir, part| match part {
+                ".." => self.nodes[dir].parent.ok_or(ErrorKind::AboveRoot),
+                name if listed.contains(&dir) && self.child(dir, name).is_none() => {
+                    let parent = self.path(dir);
+                    Err(ErrorKind::NoSuchDir(format!(
+                        "{}/{name}",
+                        parent.trim_end_matches('/')
+                    )))
+                }
+                name => self.mkdir(dir, name),
+            })
+    }
+
+    fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match &self.nodes[dir].kind {
+            Kind::Dir(children) => children.get(name).copied(),
+            Kind::File(_) => None,
+        }
+    }
+
+    /// Returns the directory `name` in `dir`, creating it if needed.
+    fn mkdir(&mut self, dir: NodeId, name: &str) -> Result<NodeId, ErrorKind> {
+        self.add(dir, name, Kind::Dir(BTreeMap::new()))
+    }
+
+    /// Adds a file to `dir`, unless the same file was already listed.
+    fn touch(&mut self, dir: NodeId, name: &str, size: u64) -> Result<NodeId, ErrorKind> {
+        self.add(dir, name, Kind::File(size))
+    }
+
+    fn add(&mut self, dir: NodeId, name: &str, kind: Kind) -> Result<NodeId, ErrorKind> {
+        let id = self.nodes.len();
+        let Kind::Dir(children) = &mut self.nodes[dir].kind else {
+            unreachable!("only directories are ever entered");
+        };
+        if let Some(&existing) = children.get(name) {
+            return match (&self.nodes[existing].kind, &kind) {
+                (Kind::Dir(_), Kind::Dir(_)) => Ok(existing),
+                (Kind::File(a), Kind::File(b)) if a == b => Ok(existing),
+                _ => Err(ErrorKind::Conflict(self.path(existing))),
+            };
+        }
+        children.insert(name.to_owned(), id);
+        self.nodes.push(Node {
+            name: name.to_owned(),
+            parent: Some(dir),
+            kind,
+            total: 0,
+        });
+        Ok(id)
+    }
+
+    /// Fills in every total in one pass from the leaves up, which works because
+    /// children are always stored after their parent.
+    fn sum_sizes(&mut self) {
+        for node in &mut self.nodes {
+            node.total = match node.kind {
+                Kind::File(size) => size,
+                Kind::Dir(_) => 0,
+            };
+        }
+        for id in (1..self.nodes.len()).rev() {
+            let total = self.nodes[id].total;
+            let parent = self.nodes[id].parent.unwrap();
+            self.nodes[parent].total += total;
+        }
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.nodes[Fs::ROOT].total
+    }
+
+    /// Finds a node by its path, like `/a/e` or `a/e`, starting from the root.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        path.split('/')
+            .filter(|part| !part.is_empty())
+            .try_fold(Fs::ROOT, |id, part| match &self.nodes[id].kind {
+                Kind::Dir(children) => children.get(part).copied(),
+                Kind::File(_) => None,
+            })
+    }
+
+    pub fn size_of(&self, path: &str) -> Option<u64> {
+        self.lookup(path).map(|id| self.nodes[id].total)
+    }
+
+    /// The absolute path of a node.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = &self.nodes[id];
+        while let Some(parent) = node.parent {
+            names.push(node.name.as_str());
+            node = &self.nodes[parent];
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// The entries of a directory sorted by name, nothing for a file.
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = (&str, NodeId)> + '_ {
+        let children = match &self.nodes[id].kind {
+            Kind::Dir(children) => Some(children),
+            Kind::File(_) => None,
+        };
+        children
+            .into_iter()
+            .flatten()
+            .map(|(name, &id)| (name.as_str(), id))
+    }
+
+    pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).filter(|&id| self.nodes[id].is_dir())
+    }
+
+    /// The `n` biggest directories, biggest first.
+    pub fn largest_dirs(&self, n: usize) -> Vec<NodeId> {
+        let mut dirs: Vec<_> = self.dirs().collect();
+        dirs.sort_by_key(|&id| std::cmp::Reverse(self.nodes[id].total));
+        dirs.truncate(n);
+        dirs
+    }
+
+    /// Visits every node depth first, with its depth below the root.
+    fn walk(&self) -> Vec<(NodeId, usize)> {
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack = vec![(Fs::ROOT, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            order.push((id, depth));
+            let children: Vec<_> = self.children(id).collect();
+            stack.extend(children.into_iter().rev().map(|(_, c)| (c, depth + 1)));
+        }
+        order
+    }
+
+    /// Every directory with its total size, subdirectories before their parent like `du`.
+    pub fn du(&self) -> String {
+        let mut out = String::new();
+        for (id, _) in self.walk().into_iter().rev() {
+            if self.nodes[id].is_dir() {
+                writeln!(out, "{}\t{}", self.nodes[id].total, self.path(id)).unwrap();
+            }
+        }
+        out
+    }
+
+    /// The whole tree, in the same layout as the puzzle description.
+    pub fn tree(&self) -> String {
+        let mut out = String::new();
+        for (id, depth) in self.walk() {
+            let node = &self.nodes[id];
+            let indent = "  ".repeat(depth);
+            match node.kind {
+                Kind::Dir(_) => writeln!(out, "{indent}- {} (dir)", node.name),
+                Kind::File(size) => writeln!(out, "{indent}- {} (file, size={size})", node.name),
+            }
+            .unwrap();
+        }
+        out
+    }
+
+    /// The tree as pretty printed JSON. Children are sorted by name, so two
+    /// transcripts that build the same filesystem give the same document.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.entry(Fs::ROOT)).expect("a tree only holds plain data")
+    }
+
+    fn entry(&self, id: NodeId) -> Entry {
+        let node = &self.nodes[id];
+        Entry {
+            name: node.name.clone(),
+            size: node.total,
+            children: node
+                .is_dir()
+                .then(|| self.children(id).map(|(_, c)| self.entry(c)).collect()),
+        }
+    }
+
+    /// Loads a tree written by [`Fs::to_json`]. Directory sizes are recomputed
+    /// and have to match the ones in the document.
+    pub fn from_json(json: &str) -> Result<Fs> {
+        let root: Entry = serde_json::from_str(json)?;
+        if root.children.is_none() {
+            return Err(anyhow!("The root `{}` is not a directory", root.name));
+        }
+        let mut fs = Fs::default();
+        let mut declared = Vec::new();
+        let mut stack = vec![(&root, Fs::ROOT)];
+        while let Some((entry, id)) = stack.pop() {
+            declared.push((id, entry.size));
+            for child in entry.children.iter().flatten() {
+                if matches!(child.name.as_str(), "" | "." | "..") || child.name.contains('/') {
+                    return Err(anyhow!("Invalid name `{}` in {}", child.name, fs.path(id)));
+                }
+                let kind = match child.children {
+                    Some(_) => Kind::Dir(BTreeMap::new()),
+                    None => Kind::File(child.size),
+                };
+                if fs.children(id).any(|(name, _)| name == child.name) {
+                    return Err(anyhow!(
+                        "Duplicate entry `{}` in {}",
+                        child.name,
+                        fs.path(id)
+                    ));
+                }
+                let child_id = fs.add(id, &child.name, kind).expect("the name is new");
+                stack.push((child, child_id));
+            }
+        }
+        fs.sum_sizes();
+        for (id, size) in declared {
+            if fs.nodes[id].total != size {
+                return Err(anyhow!(
+                    "{} has size {size}, but its contents add up to {}",
+                    fs.path(id),
+                    fs.nodes[id].total
+                ));
+            }
+        }
+        Ok(fs)
+    }
+}
+
+/// A node as it appears in the JSON export: files have no `children`, and the
+/// size of a directory is everything below it.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    name: String,
+    size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    children: Option<Vec<Entry>>,
+}
+
+pub fn p1(fs: &Fs) -> u64 {
+    fs.dirs()
+        .map(|id| fs.node(id).total_size())
+        .filter(|&size| size <= 100000)
+        .sum()
+}
+
+pub fn p2(fs: &Fs, disk: Disk) -> u64 {
+    let unused_space = disk.total - fs.total_size();
+    fs.dirs()
+        .map(|id| fs.node(id).total_size())
+        .filter(|&size| size + unused_space >= disk.needed)
+        .min()
+        .unwrap()
+}
+
+impl Solution for Day7 {
+    type Input = (Fs, Disk);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((Fs::parse(input)?, Disk::default()))
+    }
+
+    /// Accepts `disk_size` and `needed_space`, defaulting to the puzzle's values.
+    fn configure((fs, disk): &mut Self::Input, params: &Params) -> Result<()> {
+        params.only(&["disk_size", "needed_space"])?;
+        if let Some(total) = params.get("disk_size")? {
+            disk.total = total;
+        }
+        if let Some(needed) = params.get("needed_space")? {
+            disk.needed = needed;
+        }
+        let used = fs.total_size();
+        if used > disk.total {
+            return Err(anyhow!(
+                "The files take up {used}, more than the disk size of {}",
+                disk.total
+            ));
+        }
+        if disk.needed > disk.total {
+            return Err(anyhow!(
+                "Needed space {} is more than the disk size of {}",
+                disk.needed,
+                disk.total
+            ));
+        }
+        Ok(())
+    }
+
+    fn part1((fs, _): &Self::Input) -> u64 {
+        p1(fs)
+    }
+
+    fn part2((fs, disk): &Self::Input) -> u64 {
+        p2(fs, *disk)
+    }
+}
+
+#[cfg(test)]
+mod d7 {
+    use super::*;
+    use test_case::test_case;
+
+    const SAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn d7t1() {
+        let fs = dbg!(Fs::parse(SAMPLE).unwrap());
+        assert_eq!(p1(&fs), 95437);
+    }
+
+    #[test]
+    fn d7t2() {
+        let fs = Fs::parse(SAMPLE).unwrap();
+        assert_eq!(p2(&fs, Disk::default()), 24933642);
+        let disk = Disk {
+            total: 3_000_000_000,
+            needed: 700_000_000,
+        };
+        assert_eq!(p2(&fs, disk), 584);
+    }
+
+    #[test]
+    fn d7_queries() {
+        let fs = Fs::parse(SAMPLE).unwrap();
+        assert_eq!(fs.total_size(), 48381165);
+        assert_eq!(fs.size_of("/a"), Some(94853));
+        assert_eq!(fs.size_of("/a/e"), Some(584));
+        assert_eq!(fs.size_of("d/k"), Some(7214296));
+        assert_eq!(fs.size_of("/a/x"), None);
+        assert_eq!(fs.size_of("/b.txt/x"), None);
+
+        let a = fs.lookup("/a").unwrap();
+        let names: Vec<_> = fs.children(a).map(|(name, _)| name).collect();
+        assert_eq!(names, ["e", "f", "g", "h.lst"]);
+        assert_eq!(fs.path(fs.lookup("a/e/i").unwrap()), "/a/e/i");
+
+        let largest: Vec<_> = fs
+            .largest_dirs(2)
+            .into_iter()
+            .map(|id| fs.path(id))
+            .collect();
+        assert_eq!(largest, ["/", "/d"]);
+    }
+
+    #[test]
+    fn d7_print() {
+        let fs = Fs::parse(SAMPLE).unwrap();
+        assert_eq!(fs.du(), "24933642\t/d\n584\t/a/e\n94853\t/a\n48381165\t/\n");
+        assert_eq!(
+            fs.tree(),
+            "- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+"
+        );
+    }
+
+    #[test]
+    fn d7_parse_line() {
+        assert_eq!(parse_line("$ cd /a/b"), Ok(("", Line::Cd("/a/b"))));
+        assert_eq!(parse_line("$ ls"), Ok(("", Line::Ls)));
+        assert_eq!(parse_line("dir e"), Ok(("", Line::Dir("e"))));
+        assert_eq!(parse_line("584 i.txt"), Ok(("", Line::File(584, "i.txt"))));
+        assert!(parse_line("$ rm -rf").is_err());
+    }
+
+    #[test]
+    fn d7_absolute_cd_and_relisting() {
+        let fs = Fs::parse(
+            "$ cd /a/b
+$ ls
+10 x
+$ cd /
+$ ls
+dir a
+5 y
+$ cd a/b/../b
+$ ls
+10 x
+$ cd ..
+$ ls
+dir b
+1 z",
+        )
+        .unwrap();
+        assert_eq!(fs.total_size(), 16);
+        assert_eq!(fs.size_of("/a/b"), Some(10));
+        assert_eq!(fs.size_of("/a"), Some(11));
+    }
+
+    #[test_case("$ cd ..", 1, ErrorKind::AboveRoot; "above root")]
+    #[test_case("$ ls\n12 a\n$ cd /a/..", 3, ErrorKind::Conflict(String::from("/a")); "cd into file")]
+    #[test_case("$ ls\n12 a\n$ ls\n13 a", 4, ErrorKind::Conflict(String::from("/a")); "size changed")]
+    #[test_case("$ ls\ndir a\n1 a", 3, ErrorKind::Conflict(String::from("/a")); "file and dir")]
+    #[test_case("$ cd a\n1 b", 2, ErrorKind::OutputWithoutLs; "output without ls")]
+    #[test_case("$ ls\nfoo bar", 2, ErrorKind::Syntax(String::from("foo bar")); "syntax")]
+    #[test_case("$ ls\n$ cd ", 2, ErrorKind::Syntax(String::from("$ cd ")); "cd without path")]
+    #[test_case("$ cd /\n$ ls\ndir a\n1 f\n$ cd zzz\n$ ls\n5 g", 5, ErrorKind::NoSuchDir(String::from("/zzz")); "cd into unlisted")]
+    #[test_case("$ cd a\n$ ls\n$ cd /a/b", 3, ErrorKind::NoSuchDir(String::from("/a/b")); "nested cd into unlisted")]
+    #[test_case("$ cd a b", 1, ErrorKind::Syntax(String::from("$ cd a b")); "cd with space")]
+    fn d7_errors(input: &str, line: usize, kind: ErrorKind) {
+        assert_eq!(Fs::parse(input), Err(ParseError { line, kind }));
+    }
+
+    #[test]
+    fn d7_json_round_trip() {
+        let fs = Fs::parse(SAMPLE).unwrap();
+        let json = fs.to_json();
+        let loaded = Fs::from_json(&json).unwrap();
+        assert_eq!(loaded.total_size(), fs.total_size());
+        assert_eq!(p1(&loaded), 95437);
+        assert_eq!(p2(&loaded, Disk::default()), 24933642);
+        assert_eq!(loaded.to_json(), json);
+
+        let input = crate::bigboy::generate(7, 100, 1).unwrap();
+        let fs = Fs::parse(&input).unwrap();
+        let loaded = Fs::from_json(&fs.to_json()).unwrap();
+        assert_eq!(loaded.total_size(), fs.total_size());
+        assert_eq!(p1(&loaded), p1(&fs));
+        assert_eq!(loaded.tree(), fs.tree());
+    }
+
+    #[test]
+    fn d7_json_format() {
+        let fs = Fs::parse("$ ls\ndir a\n3 b").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&fs.to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "/",
+                "size": 3,
+                "children": [
+                    { "name": "a", "size": 0, "children": [] },
+                    { "name": "b", "size": 3 },
+                ],
+            })
+        );
+    }
+
+    #[test_case(r#"{"name": "/", "size": 1}"#; "root is a file")]
+    #[test_case(r#"{"name": "/", "size": 2, "children": [{"name": "a", "size": 1}]}"#; "wrong size")]
+    #[test_case(r#"{"name": "/", "size": 2, "children": [{"name": "a", "size": 1}, {"name": "a", "size": 1}]}"#; "duplicate")]
+    #[test_case(r#"{"name": "/", "size": 1, "children": [{"name": "a", "size": 1}, {"name": "a", "size": 0}]}"#; "duplicate with other size")]
+    #[test_case(r#"{"name": "/", "size": 1, "children": [{"name": "a/b", "size": 1}]}"#; "slash in name")]
+    fn d7_json_invalid(json: &str) {
+        assert!(Fs::from_json(json).is_err());
+    }
+
+    #[test]
+    fn d7_configure() {
+        let mut input = Day7::parse(SAMPLE).unwrap();
+        let params = Params::parse(&["disk_size=100000000", "needed_space=40000000"]).unwrap();
+        Day7::configure(&mut input, &params).unwrap();
+        assert_eq!(
+            input.1,
+            Disk {
+                total: 100_000_000,
+                needed: 40_000_000
+            }
+        );
+        let params = Params::parse(&["disk_size=1000"]).unwrap();
+        assert!(Day7::configure(&mut input, &params).is_err());
+        let params = Params::parse(&["disk=1"]).unwrap();
+        assert!(Day7::configure(&mut input, &params).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::grid::Direction;
+use crate::matrix::{GridError, Matrix};
+use crate::solution::Solution;
+use anyhow::Result;
+
+pub struct Day8;
+
+pub fn gen(input: &str) -> Result<Matrix<u32>, GridError> {
+    Matrix::from_str_grid(input, |c| c.to_digit(10))
+}
+
+pub fn p1(mat: &Matrix<u32>) -> u32 {
+    mat.positions()
+        .map(|pos| {
+            let tree = mat[pos];
+            u32::from(
+                Direction::ROOK
+                    .into_iter()
+                    .any(|dir| mat.ray(pos, dir).all(|(_, &other)| other < tree)),
+            )
+        })
+        .sum()
+}
+
+/// How many trees can be seen from `pos` looking in `dir`, up to and including
+/// the first one that is at least as tall.
+fn viewing_distance(mat: &Matrix<u32>, pos: (usize, usize), dir: Direction) -> u32 {
+    let tree = mat[pos];
+    let mut count = 0;
+    for (_, &other) in mat.ray(pos, dir) {
+        count += 1;
+        if other >= tree {
+            break;
+        }
+    }
+    count
+}
+
+pub fn p2(mat: &Matrix<u32>) -> u32 {
+    mat.positions()
+        .map(|pos| {
+            Direction::ROOK
+                .into_iter()
+                .map(|dir| viewing_distance(mat, pos, dir))
+                .product()
+        })
+        .max()
+        .unwrap()
+}
+
+impl Solution for Day8 {
+    type Input = Matrix<u32>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Matrix<u32>> {
+        Ok(gen(input)?)
+    }
+
+    fn part1(mat: &Matrix<u32>) -> u32 {
+        p1(mat)
+    }
+
+    fn part2(mat: &Matrix<u32>) -> u32 {
+        p2(mat)
+    }
+}
+
+#[cfg(test)]
+mod d8 {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = "30373
+25512
+65332
+33549
+35390";
+
+        let mat = dbg!(gen(input).unwrap());
+
+        assert_eq!(p1(&mat), 21);
+        assert_eq!(p2(&mat), 8);
+    }
+}
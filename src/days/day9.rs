@@ -1,13 +1,15 @@
-use std::collections::HashSet;
-use std::fs;
-use std::time::Instant;
+use crate::grid::DynamicGrid;
+use crate::solution::Solution;
+use anyhow::Result;
 
-struct Ope {
+pub struct Day9;
+
+pub struct Ope {
     dir: char,
     num: u32,
 }
 
-fn gen(input: &str) -> Vec<Ope> {
+pub fn gen(input: &str) -> Vec<Ope> {
     input
         .lines()
         .map(|line| {
@@ -19,10 +21,10 @@ fn gen(input: &str) -> Vec<Ope> {
         .collect()
 }
 
-fn p1(opes: &[Ope]) -> usize {
-    let mut hpos = (0i32, 0i32);
-    let mut tpos = (0i32, 0i32);
-    let mut res = HashSet::new();
+pub fn p1(opes: &[Ope]) -> usize {
+    let mut hpos = (0isize, 0isize);
+    let mut tpos = (0isize, 0isize);
+    let mut res = DynamicGrid::new();
     for ope in opes {
         for _ in 0..ope.num {
             match ope.dir {
@@ -59,15 +61,15 @@ fn p1(opes: &[Ope]) -> usize {
             };
             tpos.0 += to_move.0;
             tpos.1 += to_move.1;
-            res.insert(tpos);
+            res.insert(tpos.into(), ());
         }
     }
     res.len()
 }
 
-fn p2<const N: usize>(opes: &[Ope]) -> usize {
-    let mut poses = [(0i32, 0i32); N];
-    let mut res = HashSet::new();
+pub fn p2<const N: usize>(opes: &[Ope]) -> usize {
+    let mut poses = [(0isize, 0isize); N];
+    let mut res = DynamicGrid::new();
 
     for ope in opes {
         for _ in 0..ope.num {
@@ -104,32 +106,28 @@ fn p2<const N: usize>(opes: &[Ope]) -> usize {
                 tpos.1 += to_move.1;
             }
 
-            res.insert(*poses.last().unwrap());
+            res.insert((*poses.last().unwrap()).into(), ());
         }
     }
     res.len()
 }
 
-fn main() {
-    let path = "inputs/9.txt";
-    let input = fs::read_to_string(path).unwrap();
-
-    let in1 = Instant::now();
-    let opes = gen(&input);
-    let in0 = Instant::now();
-    println!("Input parsed in: {:?}", in0.duration_since(in1));
+impl Solution for Day9 {
+    type Input = Vec<Ope>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let i11 = Instant::now();
-    let res1 = p1(&opes);
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+    fn parse(input: &str) -> Result<Vec<Ope>> {
+        Ok(gen(input))
+    }
 
-    println!("-----");
+    fn part1(opes: &Vec<Ope>) -> usize {
+        p1(opes)
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2::<10>(&opes);
-    let i22 = Instant::now();
-    println!("gold: {:?}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(opes: &Vec<Ope>) -> usize {
+        p2::<10>(opes)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,552 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a grid, with `y` growing downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Pos {
+    pub const ORIGIN: Pos = Pos { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Pos { x, y }
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Both coordinates as `usize`, if neither is negative.
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(isize, isize)> for Pos {
+    fn from((x, y): (isize, isize)) -> Self {
+        Pos { x, y }
+    }
+}
+
+impl From<(i32, i32)> for Pos {
+    fn from((x, y): (i32, i32)) -> Self {
+        Pos {
+            x: x as isize,
+            y: y as isize,
+        }
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((x, y): (usize, usize)) -> Self {
+        Pos {
+            x: x as isize,
+            y: y as isize,
+        }
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Pos {
+    type Output = Pos;
+
+    fn add(self, dir: Direction) -> Pos {
+        self + dir.offset()
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, other: Pos) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Pos {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Pos) -> Pos {
+        Pos::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, other: Pos) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Pos {
+    type Output = Pos;
+
+    fn mul(self, n: isize) -> Pos {
+        Pos::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Pos {
+    type Output = Pos;
+
+    fn neg(self) -> Pos {
+        Pos::new(-self.x, -self.y)
+    }
+}
+
+/// A step to a neighbouring cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+    pub const ROOK: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    pub fn offset(self) -> Pos {
+        let (x, y) = match self {
+            Direction::N => (0, -1),
+            Direction::NE => (1, -1),
+            Direction::E => (1, 0),
+            Direction::SE => (1, 1),
+            Direction::S => (0, 1),
+            Direction::SW => (-1, 1),
+            Direction::W => (-1, 0),
+            Direction::NW => (-1, -1),
+        };
+        Pos::new(x, y)
+    }
+}
+
+/// The smallest rectangle holding every cell of a grid, both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    pub fn point(pos: Pos) -> Self {
+        Bounds { min: pos, max: pos }
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x) && (self.min.y..=self.max.y).contains(&pos.y)
+    }
+
+    /// Grows the rectangle just enough to hold `pos`.
+    pub fn include(&mut self, pos: Pos) {
+        self.min = Pos::new(self.min.x.min(pos.x), self.min.y.min(pos.y));
+        self.max = Pos::new(self.max.x.max(pos.x), self.max.y.max(pos.y));
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y) as usize + 1
+    }
+}
+
+/// Read access shared by every kind of grid, so that searches and drawing can
+/// be written once.
+pub trait Grid {
+    type Item;
+
+    /// The cell at `pos`, or `None` if it is outside the grid or empty.
+    fn get(&self, pos: Pos) -> Option<&Self::Item>;
+
+    /// Where the cells are, or `None` for an empty grid.
+    fn bounds(&self) -> Option<Bounds>;
+
+    fn contains(&self, pos: Pos) -> bool {
+        self.get(pos).is_some()
+    }
+
+    /// The positions around `pos` in the given directions that hold a cell.
+    fn neighbors_in(&self, pos: Pos, dirs: &[Direction]) -> Vec<Pos> {
+        dirs.iter()
+            .map(|&dir| pos + dir)
+            .filter(|&next| self.contains(next))
+            .collect()
+    }
+
+    /// The up to 8 positions around `pos`, diagonals included, that hold a cell.
+    fn neighbors(&self, pos: Pos) -> Vec<Pos> {
+        self.neighbors_in(pos, &Direction::ALL)
+    }
+
+    /// The up to 4 positions up, down, left and right of `pos` that hold a cell.
+    fn rook_neighbors(&self, pos: Pos) -> Vec<Pos> {
+        self.neighbors_in(pos, &Direction::ROOK)
+    }
+
+    /// Draws the bounding box one row per line, `f` gets `None` for empty cells.
+    fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(Option<&Self::Item>) -> char,
+    {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+        let mut out = String::with_capacity((bounds.width() + 1) * bounds.height());
+        for y in bounds.min.y..=bounds.max.y {
+            out.extend((bounds.min.x..=bounds.max.x).map(|x| f(self.get(Pos::new(x, y)))));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// A grid that only stores the cells that were set, for when they are few and
+/// far apart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+}
+
+impl<T> Grid for SparseGrid<T> {
+    type Item = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        let mut keys = self.cells.keys();
+        let mut bounds = Bounds::point(*keys.next()?);
+        keys.for_each(|&pos| bounds.include(pos));
+        Some(bounds)
+    }
+}
+
+/// A dense grid that grows in any direction, negative coordinates included,
+/// whenever a cell is set outside of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicGrid<T> {
+    cells: Vec<Option<T>>,
+    /// The coordinates of `cells[0]`.
+    origin: Pos,
+    width: usize,
+    height: usize,
+    len: usize,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for DynamicGrid<T> {
+    fn default() -> Self {
+        DynamicGrid {
+            cells: Vec::new(),
+            origin: Pos::ORIGIN,
+            width: 0,
+            height: 0,
+            len: 0,
+            bounds: None,
+        }
+    }
+}
+
+impl<T> DynamicGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn index(&self, pos: Pos) -> Option<usize> {
+        let (x, y) = (pos - self.origin).to_usize()?;
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    /// Makes room for `pos`, at least doubling the side that has to grow so that
+    /// walking off the edge one cell at a time stays cheap.
+    fn grow_to(&mut self, Pos { x, y }: Pos) {
+        if self.width == 0 {
+            self.origin = Pos::new(x - 4, y - 4);
+            self.width = 8;
+            self.height = 8;
+            self.cells = std::iter::repeat_with(|| None).take(64).collect();
+            return;
+        }
+        let Pos { x: ox, y: oy } = self.origin;
+        let (w, h) = (self.width as isize, self.height as isize);
+        let min_x = if x < ox { x.min(ox - w) } else { ox };
+        let max_x = if x >= ox + w {
+            x.max(ox + 2 * w - 1)
+        } else {
+            ox + w - 1
+        };
+        let min_y = if y < oy { y.min(oy - h) } else { oy };
+        let max_y = if y >= oy + h {
+            y.max(oy + 2 * h - 1)
+        } else {
+            oy + h - 1
+        };
+
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut cells: Vec<Option<T>> = std::iter::repeat_with(|| None)
+            .take(width * height)
+            .collect();
+        let (dx, dy) = ((ox - min_x) as usize, (oy - min_y) as usize);
+        for (i, cell) in std::mem::take(&mut self.cells).into_iter().enumerate() {
+            let (cx, cy) = (i % self.width, i / self.width);
+            cells[cx + dx + (cy + dy) * width] = cell;
+        }
+        self.cells = cells;
+        self.origin = Pos::new(min_x, min_y);
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        let idx = match self.index(pos) {
+            Some(idx) => idx,
+            None => {
+                self.grow_to(pos);
+                self.index(pos).unwrap()
+            }
+        };
+        let old = self.cells[idx].replace(value);
+        if old.is_none() {
+            self.len += 1;
+            match &mut self.bounds {
+                Some(bounds) => bounds.include(pos),
+                None => self.bounds = Some(Bounds::point(pos)),
+            }
+        }
+        old
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let idx = self.index(pos)?;
+        self.cells[idx].as_mut()
+    }
+
+    /// How many cells are set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().enumerate().filter_map(|(i, cell)| {
+            let pos = self.origin + Pos::from((i % self.width, i / self.width));
+            cell.as_ref().map(|value| (pos, value))
+        })
+    }
+}
+
+impl<T> Grid for DynamicGrid<T> {
+    type Item = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells[self.index(pos)?].as_ref()
+    }
+
+    /// Only covers the cells that were set, not the room kept for growing.
+    fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn test_pos() {
+        let pos = Pos::from((2, 3));
+        assert_eq!(pos + Direction::NW, Pos::new(1, 2));
+        assert_eq!(pos - Pos::new(5, 1), Pos::new(-3, 2));
+        assert_eq!(Direction::E.offset() * 3, Pos::new(3, 0));
+        assert_eq!(-pos, Pos::new(-2, -3));
+        assert_eq!(pos.manhattan(Pos::new(-1, 4)), 4);
+        assert_eq!(pos.to_usize(), Some((2, 3)));
+        assert_eq!(Pos::new(-1, 0).to_usize(), None);
+        assert_eq!(Pos::from((1usize, 2usize)).to_string(), "(1, 2)");
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut bounds = Bounds::point(Pos::new(1, 1));
+        bounds.include(Pos::new(-2, 3));
+        assert_eq!(bounds.min, Pos::new(-2, 1));
+        assert_eq!(bounds.max, Pos::new(1, 3));
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+        assert!(bounds.contains(Pos::new(0, 2)));
+        assert!(!bounds.contains(Pos::ORIGIN));
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Pos::new(-3, 5), 'a');
+        grid.insert(Pos::new(2, -1), 'b');
+        assert_eq!(grid.get(Pos::new(-3, 5)), Some(&'a'));
+        assert_eq!(grid.get(Pos::ORIGIN), None);
+        assert_eq!(grid.neighbors(Pos::new(-2, 4)), [Pos::new(-3, 5)]);
+        assert_eq!(grid.rook_neighbors(Pos::new(-2, 4)), []);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Pos::new(-3, -1),
+                max: Pos::new(2, 5)
+            })
+        );
+        assert_eq!(grid.remove(Pos::new(2, -1)), Some('b'));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn test_dynamic_grows_everywhere() {
+        let mut grid = DynamicGrid::new();
+        let positions = [(0, 0), (-20, 3), (15, -7), (100, 100), (-1, -1)].map(Pos::from);
+        for (i, &pos) in positions.iter().enumerate() {
+            assert_eq!(grid.insert(pos, i), None);
+        }
+        for (i, &pos) in positions.iter().enumerate() {
+            assert_eq!(grid.get(pos), Some(&i));
+        }
+        assert_eq!(grid.insert(Pos::ORIGIN, 9), Some(0));
+        assert_eq!(grid.len(), positions.len());
+        assert_eq!(grid.get(Pos::new(1, 1)), None);
+        assert_eq!(grid.get(Pos::new(-1000, 0)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Pos::new(-20, -7),
+                max: Pos::new(100, 100)
+            })
+        );
+        let mut cells: Vec<_> = grid.iter().map(|(pos, &v)| (pos, v)).collect();
+        cells.sort();
+        assert_eq!(cells[0], (Pos::new(-20, 3), 1));
+    }
+
+    /// Counts the cells reachable from the top left corner, for any grid.
+    fn flood<G: Grid<Item = char>>(grid: &G) -> usize {
+        let mut seen = vec![Pos::ORIGIN];
+        let mut todo = vec![Pos::ORIGIN];
+        while let Some(pos) = todo.pop() {
+            for next in grid.rook_neighbors(pos) {
+                if grid.get(next) == Some(&'.') && !seen.contains(&next) {
+                    seen.push(next);
+                    todo.push(next);
+                }
+            }
+        }
+        seen.len()
+    }
+
+    #[test]
+    fn test_generic() {
+        let matrix = Matrix::from_str_grid("..#\n#..\n..#\n", Some).unwrap();
+        let mut sparse = SparseGrid::new();
+        let mut dynamic = DynamicGrid::new();
+        for (pos, &c) in matrix.positions().map(|pos| (Pos::from(pos), &matrix[pos])) {
+            sparse.insert(pos, c);
+            dynamic.insert(pos, c);
+        }
+        assert_eq!(flood(&matrix), 6);
+        assert_eq!(flood(&sparse), 6);
+        assert_eq!(flood(&dynamic), 6);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = DynamicGrid::new();
+        grid.insert(Pos::new(-1, 0), '#');
+        grid.insert(Pos::new(1, 1), '#');
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "#..\n..#\n");
+
+        let matrix = Matrix::new([1, 0, 0, 1], 2, 2);
+        assert_eq!(
+            matrix.render(|c| if c == Some(&1) { '#' } else { '.' }),
+            "#.\n.#\n"
+        );
+        assert_eq!(
+            matrix.neighbors(Pos::ORIGIN),
+            [Pos::new(1, 0), Pos::new(1, 1), Pos::new(0, 1)]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+/// Where to read a day's puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// `inputs/N.txt`
+    Default,
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/{day}.txt"))
+}
+
+pub fn read(day: u8, source: &Source) -> Result<String> {
+    match source {
+        Source::Default => read_file(&default_path(day)),
+        Source::File(path) => read_file(path),
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .context("Failed to read input from stdin")?;
+            Ok(input)
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => anyhow!(
+            "Input file {} does not exist, pass `--input <path>` or `--input -` for stdin",
+            path.display()
+        ),
+        _ => anyhow!(e).context(format!("Failed to read input file {}", path.display())),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("bigboys/7/bigboy.txt"),
+            Source::File(PathBuf::from("bigboys/7/bigboy.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = read(7, &Source::from_arg("does/not/exist.txt")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("does/not/exist.txt does not exist"));
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(default_path(12), PathBuf::from("inputs/12.txt"));
+        assert!(read(1, &Source::Default).is_ok());
+    }
+}
@@ -0,0 +1,227 @@
+/// A set of integers stored as sorted, disjoint and non-adjacent closed
+/// intervals `[lo, hi]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<(i64, i64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer in `[lo, hi]`, merging with the intervals it
+    /// overlaps or touches. Does nothing if `lo > hi`.
+    pub fn insert(&mut self, lo: i64, hi: i64) {
+        if lo > hi {
+            return;
+        }
+        // The first interval that could merge, and the first one past the end.
+        let start = self
+            .intervals
+            .partition_point(|&(_, h)| h < lo.saturating_sub(1));
+        let end = self
+            .intervals
+            .partition_point(|&(l, _)| l <= hi.saturating_add(1));
+        let merged = match self.intervals[start..end] {
+            [] => (lo, hi),
+            ref touched => (lo.min(touched[0].0), hi.max(touched[touched.len() - 1].1)),
+        };
+        self.intervals.splice(start..end, [merged]);
+    }
+
+    /// Takes out every integer in `[lo, hi]`.
+    pub fn remove(&mut self, lo: i64, hi: i64) {
+        if lo > hi {
+            return;
+        }
+        let start = self.intervals.partition_point(|&(_, h)| h < lo);
+        let end = self.intervals.partition_point(|&(l, _)| l <= hi);
+        if start == end {
+            return;
+        }
+        // Only the outer two intervals can stick out of `[lo, hi]`, and nothing
+        // does past either end of `i64`.
+        let (first, _) = self.intervals[start];
+        let (_, last) = self.intervals[end - 1];
+        let kept = [
+            lo.checked_sub(1).map(|h| (first, h)),
+            hi.checked_add(1).map(|l| (l, last)),
+        ];
+        self.intervals.splice(
+            start..end,
+            kept.into_iter().flatten().filter(|(l, h)| l <= h),
+        );
+    }
+
+    /// Everything in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    /// Everything in `self` that is not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for (lo, hi) in other.iter() {
+            result.remove(lo, hi);
+        }
+        result
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|&(_, h)| h < x);
+        self.intervals.get(i).is_some_and(|&(l, _)| l <= x)
+    }
+
+    /// How many integers are in the set, or `u64::MAX` if that does not fit.
+    pub fn len(&self) -> u64 {
+        self.intervals
+            .iter()
+            .map(|&(lo, hi)| hi.abs_diff(lo).saturating_add(1))
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// The intervals of `[lo, hi]` that are not in the set, none if `lo > hi`.
+    pub fn gaps(&self, lo: i64, hi: i64) -> Vec<(i64, i64)> {
+        let mut gaps = Vec::new();
+        if lo > hi {
+            return gaps;
+        }
+        let mut next = lo;
+        for (l, h) in self.iter() {
+            if h < next {
+                continue;
+            }
+            if l > hi {
+                break;
+            }
+            if l > next {
+                gaps.push((next, l - 1));
+            }
+            match h.checked_add(1) {
+                Some(after) if after <= hi => next = after,
+                _ => return gaps,
+            }
+        }
+        gaps.push((next, hi));
+        gaps
+    }
+}
+
+impl FromIterator<(i64, i64)> for IntervalSet {
+    /// Sorts all the intervals once and merges them in a single pass, which is
+    /// cheaper than inserting them one by one.
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> Self {
+        let mut all: Vec<_> = iter.into_iter().filter(|(lo, hi)| lo <= hi).collect();
+        all.sort_unstable();
+        let mut intervals: Vec<(i64, i64)> = Vec::with_capacity(all.len());
+        for (lo, hi) in all {
+            match intervals.last_mut() {
+                Some(last) if lo <= last.1.saturating_add(1) => last.1 = last.1.max(hi),
+                _ => intervals.push((lo, hi)),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut s = IntervalSet::new();
+        s.insert(5, 7);
+        s.insert(0, 1);
+        s.insert(10, 12);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(0, 1), (5, 7), (10, 12)]);
+        s.insert(2, 3);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(0, 3), (5, 7), (10, 12)]);
+        s.insert(6, 10);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(0, 3), (5, 12)]);
+        s.insert(-5, 20);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(-5, 20)]);
+        s.insert(3, 1);
+        assert_eq!(s.len(), 26);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let s = set(&[(8, 9), (-2, 2), (3, 3), (1, 1), (11, 11), (5, 4)]);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(-2, 3), (8, 9), (11, 11)]);
+        assert_eq!(s.len(), 9);
+        assert!(s.contains(-2) && s.contains(9) && !s.contains(10) && !s.contains(4));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[(0, 10), (20, 30)]);
+        s.remove(3, 4);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(0, 2), (5, 10), (20, 30)]);
+        s.remove(8, 25);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(0, 2), (5, 7), (26, 30)]);
+        s.remove(-10, 0);
+        s.remove(26, 40);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(1, 2), (5, 7)]);
+        s.remove(12, 14);
+        assert_eq!(s.len(), 5);
+    }
+
+    #[test]
+    fn test_union_difference() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(4, 11)]);
+        assert_eq!(a.union(&b), set(&[(0, 15)]));
+        assert_eq!(a.difference(&b), set(&[(0, 3), (12, 15)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9)]));
+    }
+
+    #[test]
+    fn test_gaps() {
+        let s = set(&[(0, 3), (6, 7), (12, 20)]);
+        assert_eq!(s.gaps(0, 20), [(4, 5), (8, 11)]);
+        assert_eq!(s.gaps(-2, 25), [(-2, -1), (4, 5), (8, 11), (21, 25)]);
+        assert_eq!(s.gaps(1, 2), []);
+        assert_eq!(s.gaps(9, 10), [(9, 10)]);
+        assert_eq!(IntervalSet::new().gaps(1, 2), [(1, 2)]);
+        assert_eq!(s.gaps(5, 4), []);
+        assert_eq!(IntervalSet::new().gaps(10, 0), []);
+    }
+
+    #[test]
+    fn test_bounds() {
+        const MIN: i64 = i64::MIN;
+        const MAX: i64 = i64::MAX;
+        let mut s = set(&[(MIN, MAX)]);
+        assert_eq!(s.len(), u64::MAX);
+        assert_eq!(s.gaps(MIN, MAX), []);
+        assert_eq!(s.gaps(MAX, MAX), []);
+
+        s.remove(MIN, -1);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(0, MAX)]);
+        assert_eq!(s.len(), MAX as u64 + 1);
+        s.remove(10, MAX);
+        assert_eq!(s.iter().collect::<Vec<_>>(), [(0, 9)]);
+        s.remove(MIN, MAX);
+        assert!(s.is_empty());
+
+        let s = set(&[(MIN, MIN), (MAX - 1, MAX)]);
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.gaps(MIN, MAX), [(MIN + 1, MAX - 2)]);
+        assert_eq!(s.gaps(MAX - 3, MAX), [(MAX - 3, MAX - 2)]);
+        assert_eq!(IntervalSet::new().gaps(MAX, MAX), [(MAX, MAX)]);
+    }
+}
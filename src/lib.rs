@@ -1 +1,10 @@
+pub mod bigboy;
+pub mod days;
+pub mod grid;
+pub mod input;
+pub mod interval;
 pub mod matrix;
+pub mod report;
+pub mod search;
+pub mod solution;
+pub mod verify;
@@ -1,6 +1,86 @@
+use crate::grid::{Bounds, Direction, Grid, Pos};
 use std::fmt;
 use std::ops;
 
+/// Why a text grid could not be turned into a [`Matrix`]. Lines and columns are 1-based.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidChar {
+        line: usize,
+        column: usize,
+        c: char,
+    },
+    MissingMarker(char),
+    DuplicateMarker {
+        c: char,
+        line: usize,
+        column: usize,
+        first_line: usize,
+        first_column: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "the grid is empty"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is {found} wide, but the first line is {expected} wide"
+            ),
+            GridError::InvalidChar { line, column, c } => {
+                write!(f, "unexpected {c:?} at line {line}, column {column}")
+            }
+            GridError::MissingMarker(c) => write!(f, "there is no {c:?} in the grid"),
+            GridError::DuplicateMarker {
+                c,
+                line,
+                column,
+                first_line,
+                first_column,
+            } => write!(
+                f,
+                "another {c:?} at line {line}, column {column}, \
+                 the first one is at line {first_line}, column {first_column}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// The cells from a starting point to the edge in one direction, without the
+/// start itself. See [`Matrix::ray`].
+pub struct Ray<'a, T> {
+    matrix: &'a Matrix<T>,
+    pos: (usize, usize),
+    offset: Pos,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.pos.0.checked_add_signed(self.offset.x)?;
+        let y = self.pos.1.checked_add_signed(self.offset.y)?;
+        if x >= self.matrix.width || y >= self.matrix.height {
+            return None;
+        }
+        self.pos = (x, y);
+        Some(((x, y), &self.matrix.vec[x + y * self.matrix.width]))
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Matrix<T> {
     pub vec: Vec<T>,
@@ -19,30 +99,26 @@ impl<T> Matrix<T> {
             height,
         }
     }
-    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
-        if x < 0
-            || x > (self.width - 1).try_into().unwrap()
-            || y < 0
-            || y > (self.height - 1).try_into().unwrap()
-        {
-            return None;
+    /// Where `(x, y)` is in `vec`, panicking with a readable message when it is
+    /// outside the matrix.
+    fn offset(&self, x: usize, y: usize) -> usize {
+        if x >= self.width || y >= self.height {
+            panic!(
+                "Index ({x}, {y}) out of range for Matrix with size ({}, {})",
+                self.width, self.height
+            );
         }
-        let x: usize = x.try_into().unwrap();
-        let y: usize = y.try_into().unwrap();
-        self.vec.get(x + y * self.width)
+        x + y * self.width
     }
 
-    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
-        if x < 0
-            || x > (self.width - 1).try_into().unwrap()
-            || y < 0
-            || y > (self.height - 1).try_into().unwrap()
-        {
-            return None;
-        }
-        let x: usize = x.try_into().unwrap();
-        let y: usize = y.try_into().unwrap();
-        self.vec.get_mut(x + y * self.width)
+    fn checked_offset(&self, pos: Pos) -> Option<usize> {
+        let (x, y) = pos.to_usize()?;
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let idx = self.checked_offset(pos)?;
+        self.vec.get_mut(idx)
     }
 
     pub fn rook_neighbor_indices(
@@ -168,6 +244,124 @@ impl<T> Matrix<T> {
         self.vec.swap(idx_a, idx_b)
     }
 
+    /// Parses a grid of characters, one row per line, mapping each character
+    /// with `f`. Every line has to be as wide as the first one, and `f` returning
+    /// `None` rejects the character.
+    pub fn from_str_grid<F>(input: &str, f: F) -> Result<Self, GridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        Self::from_str_grid_with_markers(input, [], f).map(|(matrix, [])| matrix)
+    }
+
+    /// Like [`Matrix::from_str_grid`], and also returns the `(x, y)` position of
+    /// each marker, such as the `S` and `E` of a maze. Every marker has to appear
+    /// exactly once; it is still passed to `f` like any other character.
+    pub fn from_str_grid_with_markers<F, const N: usize>(
+        input: &str,
+        markers: [char; N],
+        mut f: F,
+    ) -> Result<(Self, [(usize, usize); N]), GridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut found: [Option<(usize, usize)>; N] = [None; N];
+        let mut vec = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                if let Some(i) = markers.iter().position(|&m| m == c) {
+                    if let Some(first) = found[i] {
+                        return Err(GridError::DuplicateMarker {
+                            c,
+                            line: y + 1,
+                            column: x + 1,
+                            first_line: first.1 + 1,
+                            first_column: first.0 + 1,
+                        });
+                    }
+                    found[i] = Some((x, y));
+                }
+                let item = f(c).ok_or(GridError::InvalidChar {
+                    line: y + 1,
+                    column: x + 1,
+                    c,
+                })?;
+                vec.push(item);
+                row_width += 1;
+            }
+            let expected = *width.get_or_insert(row_width);
+            if row_width != expected {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected,
+                    found: row_width,
+                });
+            }
+            height += 1;
+        }
+        let width = match width {
+            Some(width) if width > 0 => width,
+            _ => return Err(GridError::Empty),
+        };
+        let mut positions = [(0, 0); N];
+        for (i, pos) in found.into_iter().enumerate() {
+            positions[i] = pos.ok_or(GridError::MissingMarker(markers[i]))?;
+        }
+        Ok((Matrix { vec, width, height }, positions))
+    }
+
+    /// Every `(x, y)` position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.vec[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.vec[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.vec.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "Column {x} out of range for width {}",
+            self.width
+        );
+        self.vec.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn column_mut(&mut self, x: usize) -> impl Iterator<Item = &mut T> {
+        assert!(
+            x < self.width,
+            "Column {x} out of range for width {}",
+            self.width
+        );
+        self.vec.iter_mut().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Walks from `from` towards the edge in direction `dir`, nearest cell first.
+    pub fn ray(&self, from: (usize, usize), dir: Direction) -> Ray<'_, T> {
+        Ray {
+            matrix: self,
+            pos: from,
+            offset: dir.offset(),
+        }
+    }
+
     pub fn index_of<F>(&self, f: F) -> Option<(usize, usize)>
     where
         F: FnMut(&T) -> bool,
@@ -179,6 +373,21 @@ impl<T> Matrix<T> {
     }
 }
 
+impl<T> Grid for Matrix<T> {
+    type Item = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        self.vec.get(self.checked_offset(pos)?)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (!self.is_empty()).then(|| Bounds {
+            min: Pos::ORIGIN,
+            max: Pos::from((self.width - 1, self.height - 1)),
+        })
+    }
+}
+
 impl<T> fmt::Debug for Matrix<T>
 where
     T: fmt::Debug,
@@ -202,51 +411,34 @@ impl<T> ops::Index<(usize, usize)> for Matrix<T> {
     type Output = T;
 
     fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
-        if x > self.width - 1 || y > self.height - 1 {
-            panic!(
-                "Index ({}, {}) out of range for Matrix with size ({}, {})",
-                y, x, self.height, self.width
-            );
-        }
-        &self.vec[x + y * self.width]
+        &self.vec[self.offset(x, y)]
     }
 }
 
 impl<T> ops::IndexMut<(usize, usize)> for Matrix<T> {
     fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
-        if x > self.width - 1 || y > self.height - 1 {
-            panic!(
-                "Index ({}, {}) out of range for Matrix with size ({}, {})",
-                y, x, self.height, self.width
-            );
-        }
-        &mut self.vec[x + y * self.width]
+        let idx = self.offset(x, y);
+        &mut self.vec[idx]
     }
 }
 
-impl<T> ops::Index<(&usize, &usize)> for Matrix<T> {
+impl<T> ops::Index<Pos> for Matrix<T> {
     type Output = T;
 
-    fn index(&self, (x, y): (&usize, &usize)) -> &Self::Output {
-        if *x > self.width - 1 || *y > self.height - 1 {
-            panic!(
-                "Index ({}, {}) out of range for Matrix with size ({}, {})",
-                y, x, self.height, self.width
-            );
+    fn index(&self, pos: Pos) -> &Self::Output {
+        match pos.to_usize() {
+            Some((x, y)) => &self[(x, y)],
+            None => panic!("Index {pos} out of range for Matrix"),
         }
-        &self.vec[x + y * self.width]
     }
 }
 
-impl<T> ops::IndexMut<(&usize, &usize)> for Matrix<T> {
-    fn index_mut(&mut self, (x, y): (&usize, &usize)) -> &mut Self::Output {
-        if *x > self.width - 1 || *y > self.height - 1 {
-            panic!(
-                "Index ({}, {}) out of range for Matrix with size ({}, {})",
-                y, x, self.height, self.width
-            );
+impl<T> ops::IndexMut<Pos> for Matrix<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        match pos.to_usize() {
+            Some((x, y)) => &mut self[(x, y)],
+            None => panic!("Index {pos} out of range for Matrix"),
         }
-        &mut self.vec[x + y * self.width]
     }
 }
 
@@ -261,4 +453,108 @@ mod test {
         assert_eq!(matrix[(0, 0)], 3);
         assert_eq!(matrix[(0, 1)], 0);
     }
+
+    #[test]
+    fn test_index() {
+        let mut matrix = Matrix::new(0..6, 3, 2);
+        assert_eq!(matrix[(2, 1)], 5);
+        assert_eq!(matrix[Pos::new(1, 1)], 4);
+        assert_eq!(matrix.get(Pos::new(3, 0)), None);
+        assert_eq!(matrix.get(Pos::new(-1, 0)), None);
+        *matrix.get_mut(Pos::new(0, 1)).unwrap() = 9;
+        matrix[Pos::new(2, 0)] = 8;
+        assert_eq!(matrix.vec, [0, 1, 8, 9, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (3, 1) out of range for Matrix with size (3, 2)")]
+    fn test_index_panic() {
+        let matrix = Matrix::new(0..6, 3, 2);
+        let _ = matrix[(3, 1)];
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut matrix = Matrix::new(0..6, 3, 2);
+        assert_eq!(matrix.row(1), [3, 4, 5]);
+        assert_eq!(matrix.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 4, 5]]);
+        assert_eq!(matrix.column(1).copied().collect::<Vec<_>>(), [1, 4]);
+        let columns: Vec<Vec<_>> = matrix.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, [[0, 3], [1, 4], [2, 5]]);
+
+        matrix.row_mut(0).fill(9);
+        matrix.column_mut(2).for_each(|v| *v = 7);
+        assert_eq!(matrix.vec, [9, 9, 7, 3, 4, 7]);
+    }
+
+    #[test]
+    fn test_ray() {
+        let matrix = Matrix::new(0..12, 4, 3);
+        let ray = |from, dir| matrix.ray(from, dir).collect::<Vec<_>>();
+        assert_eq!(ray((1, 1), Direction::E), [((2, 1), &6), ((3, 1), &7)]);
+        assert_eq!(ray((1, 1), Direction::W), [((0, 1), &4)]);
+        assert_eq!(ray((1, 1), Direction::N), [((1, 0), &1)]);
+        assert_eq!(ray((1, 1), Direction::SE), [((2, 2), &10)]);
+        assert_eq!(ray((1, 1), Direction::NW), [((0, 0), &0)]);
+        assert_eq!(ray((0, 2), Direction::NE), [((1, 1), &5), ((2, 0), &2)]);
+        assert_eq!(ray((0, 2), Direction::S), []);
+        assert_eq!(matrix.positions().nth(5), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_from_str_grid() {
+        let matrix = Matrix::from_str_grid("12\n34\n56\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!((matrix.width(), matrix.height()), (2, 3));
+        assert_eq!(matrix[(1, 2)], 6);
+
+        assert_eq!(
+            Matrix::from_str_grid("12\n345\n", |c| c.to_digit(10)),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Matrix::from_str_grid("12\n3x\n", |c| c.to_digit(10)),
+            Err(GridError::InvalidChar {
+                line: 2,
+                column: 2,
+                c: 'x'
+            })
+        );
+        assert_eq!(
+            Matrix::from_str_grid("", |c| c.to_digit(10)),
+            Err(GridError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_from_str_grid_with_markers() {
+        let (matrix, [start, end]) =
+            Matrix::from_str_grid_with_markers("S.\n.E\n", ['S', 'E'], |c| Some(c == '.')).unwrap();
+        assert_eq!((start, end), ((0, 0), (1, 1)));
+        assert!(matrix[(1, 0)]);
+
+        assert_eq!(
+            Matrix::from_str_grid_with_markers("S.\n..\n", ['S', 'E'], Some),
+            Err(GridError::MissingMarker('E'))
+        );
+        assert_eq!(
+            Matrix::from_str_grid_with_markers("S.\n.S\n", ['S'], Some),
+            Err(GridError::DuplicateMarker {
+                c: 'S',
+                line: 2,
+                column: 2,
+                first_line: 1,
+                first_column: 1,
+            })
+        );
+        assert_eq!(
+            Matrix::from_str_grid_with_markers(".S.S\n....\n", ['S'], Some)
+                .unwrap_err()
+                .to_string(),
+            "another 'S' at line 1, column 4, the first one is at line 1, column 2"
+        );
+    }
 }
@@ -0,0 +1,165 @@
+use crate::solution::Run;
+use itertools::Itertools;
+use serde::Serialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs, thread};
+
+/// Where a run happened, so timings from different machines are not compared blindly.
+#[derive(Clone, Debug, Serialize)]
+pub struct Machine {
+    pub hostname: String,
+    pub os: String,
+    pub arch: String,
+    pub cpus: usize,
+}
+
+impl Machine {
+    pub fn current() -> Self {
+        let hostname = env::var("HOSTNAME")
+            .ok()
+            .or_else(|| fs::read_to_string("/etc/hostname").ok())
+            .map(|h| h.trim().to_owned())
+            .filter(|h| !h.is_empty())
+            .unwrap_or_else(|| String::from("unknown"));
+        Machine {
+            hostname,
+            os: env::consts::OS.to_owned(),
+            arch: env::consts::ARCH.to_owned(),
+            cpus: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+/// One line of the timing report. Durations are in nanoseconds, parts that were
+/// not run are left empty.
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    pub timestamp: u64,
+    pub day: u8,
+    pub input_hash: String,
+    pub parse_ns: u64,
+    pub part1: Option<String>,
+    pub part1_ns: Option<u64>,
+    pub part2: Option<String>,
+    pub part2_ns: Option<u64>,
+    #[serde(flatten)]
+    pub machine: Machine,
+}
+
+pub const CSV_HEADER: &str =
+    "timestamp,day,input_hash,parse_ns,part1,part1_ns,part2,part2_ns,hostname,os,arch,cpus";
+
+impl Report {
+    pub fn new(run: &Run, input: &str, machine: &Machine) -> Self {
+        Report {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            day: run.day,
+            input_hash: format!("{:016x}", fnv1a(input.as_bytes())),
+            parse_ns: run.parse_time.as_nanos() as u64,
+            part1: run.part1.as_ref().map(|a| a.value.clone()),
+            part1_ns: run.part1.as_ref().map(|a| a.time.as_nanos() as u64),
+            part2: run.part2.as_ref().map(|a| a.value.clone()),
+            part2_ns: run.part2.as_ref().map(|a| a.time.as_nanos() as u64),
+            machine: machine.clone(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a report only holds plain data")
+    }
+
+    pub fn to_csv(&self) -> String {
+        let opt = |o: &Option<u64>| o.map(|v| v.to_string()).unwrap_or_default();
+        let fields = [
+            self.timestamp.to_string(),
+            self.day.to_string(),
+            self.input_hash.clone(),
+            self.parse_ns.to_string(),
+            self.part1.clone().unwrap_or_default(),
+            opt(&self.part1_ns),
+            self.part2.clone().unwrap_or_default(),
+            opt(&self.part2_ns),
+            self.machine.hostname.clone(),
+            self.machine.os.clone(),
+            self.machine.arch.clone(),
+            self.machine.cpus.to_string(),
+        ];
+        fields.iter().map(|f| csv_escape(f)).join(",")
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// 64-bit FNV-1a, stable across platforms and compiler versions unlike `DefaultHasher`.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::Answer;
+    use std::time::Duration;
+
+    fn report() -> Report {
+        let run = Run {
+            day: 10,
+            parse_time: Duration::from_nanos(1500),
+            part1: Some(Answer {
+                value: String::from("13140"),
+                detail: None,
+                time: Duration::from_nanos(42),
+            }),
+            part2: Some(Answer {
+                value: String::from("#.\n.#"),
+                detail: None,
+                time: Duration::from_micros(3),
+            }),
+        };
+        let machine = Machine {
+            hostname: String::from("box"),
+            os: String::from("linux"),
+            arch: String::from("x86_64"),
+            cpus: 8,
+        };
+        Report::new(&run, "noop", &machine)
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = report().to_csv();
+        let (_, rest) = csv.split_once(',').unwrap();
+        assert_eq!(
+            rest,
+            format!(
+                "10,{:016x},1500,13140,42,\"#.\n.#\",3000,box,linux,x86_64,8",
+                fnv1a(b"noop")
+            )
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value = serde_json::from_str(&report().to_json()).unwrap();
+        assert_eq!(json["day"], 10);
+        assert_eq!(json["parse_ns"], 1500);
+        assert_eq!(json["part2"], "#.\n.#");
+        assert_eq!(json["hostname"], "box");
+    }
+}
@@ -0,0 +1,275 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the distance to every node it reached and the node it
+/// came from, so that paths can be rebuilt.
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    pub dist: HashMap<N, C>,
+    pub prev: HashMap<N, N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy,
+{
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// The nodes from a start to `goal`, both included.
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        self.dist.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth first search where every edge costs 1. All the `starts` are at
+/// distance 0.
+pub fn bfs<N, I, F>(starts: impl IntoIterator<Item = N>, mut successors: F) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+{
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !dist.contains_key(&start) {
+            dist.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let d = dist[&node];
+        for next in successors(&node) {
+            if !dist.contains_key(&next) {
+                dist.insert(next.clone(), d + 1);
+                prev.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    Search { dist, prev }
+}
+
+/// A node waiting in the queue, ordered so that `BinaryHeap` pops the cheapest first.
+struct State<N, C> {
+    node: N,
+    cost: C,
+    /// The cost plus the estimate to the goal, equal to `cost` for Dijkstra.
+    priority: C,
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cheapest distances from the `starts` when `successors` gives each neighbour
+/// with the cost of getting there. Costs must not be negative, and
+/// `C::default()` is taken as zero.
+pub fn dijkstra<N, C, I, F>(starts: impl IntoIterator<Item = N>, mut successors: F) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+{
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        dist.insert(start.clone(), C::default());
+        queue.push(State {
+            node: start,
+            cost: C::default(),
+            priority: C::default(),
+        });
+    }
+    while let Some(State { node, cost, .. }) = queue.pop() {
+        if dist.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let cost = cost + step;
+            if dist.get(&next).is_none_or(|&best| cost < best) {
+                dist.insert(next.clone(), cost);
+                prev.insert(next.clone(), node.clone());
+                queue.push(State {
+                    node: next,
+                    cost,
+                    priority: cost,
+                });
+            }
+        }
+    }
+    Search { dist, prev }
+}
+
+/// The cheapest path from `start` to the first node accepted by `is_goal`, and
+/// its cost. `heuristic` must never overestimate the remaining cost, otherwise
+/// the path found may not be the cheapest.
+pub fn astar<N, C, I, F, H, G>(
+    start: N,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search {
+        dist: HashMap::from([(start.clone(), C::default())]),
+        prev: HashMap::new(),
+    };
+    let mut queue = BinaryHeap::new();
+    queue.push(State {
+        priority: heuristic(&start),
+        node: start,
+        cost: C::default(),
+    });
+    while let Some(State { node, cost, .. }) = queue.pop() {
+        if search.dist.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((search.path_to(&node).unwrap(), cost));
+        }
+        for (next, step) in successors(&node) {
+            let cost = cost + step;
+            if search.dist.get(&next).is_none_or(|&best| cost < best) {
+                search.dist.insert(next.clone(), cost);
+                search.prev.insert(next.clone(), node.clone());
+                queue.push(State {
+                    priority: cost + heuristic(&next),
+                    node: next,
+                    cost,
+                });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::{day12, day16};
+
+    /// 1 -> 2 -> 4 costs 1 + 5, 1 -> 3 -> 4 costs 2 + 1.
+    fn weighted(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            1 => vec![(2, 1), (3, 2)],
+            2 => vec![(4, 5)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([1], |n| weighted(n).into_iter().map(|(n, _)| n));
+        assert_eq!(search.distance(&4), Some(2));
+        assert_eq!(search.distance(&5), None);
+        assert_eq!(search.path_to(&4).unwrap().len(), 3);
+        assert_eq!(search.path_to(&1), Some(vec![1]));
+
+        let search = bfs([2, 3], |n| weighted(n).into_iter().map(|(n, _)| n));
+        assert_eq!(search.distance(&4), Some(1));
+        assert_eq!(search.distance(&1), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra([1], weighted);
+        assert_eq!(search.distance(&4), Some(3));
+        assert_eq!(search.path_to(&4), Some(vec![1, 3, 4]));
+    }
+
+    #[test]
+    fn test_astar() {
+        assert_eq!(
+            astar(1, weighted, |_| 0, |&n| n == 4),
+            Some((vec![1, 3, 4], 3))
+        );
+        assert_eq!(astar(2, weighted, |_| 0, |&n| n == 1), None);
+    }
+
+    #[test]
+    fn test_day12_sample() {
+        let (mat, start, end) = day12::gen(day12::d12::SAMPLE).unwrap();
+        let mat = &mat;
+        let climb = |&(x, y): &(usize, usize)| {
+            let here = mat[(x, y)];
+            mat.rook_neighbor_indices(x, y)
+                .filter(move |&next| mat[next] <= here + 1)
+        };
+        assert_eq!(bfs([start], climb).distance(&end), Some(31));
+        assert_eq!(
+            dijkstra([start], |pos| climb(pos).map(|next| (next, 1))).distance(&end),
+            Some(31)
+        );
+        let manhattan = |&(x, y): &(usize, usize)| x.abs_diff(end.0) + y.abs_diff(end.1);
+        let (path, cost) = astar(
+            start,
+            |pos| climb(pos).map(|next| (next, 1)).collect::<Vec<_>>(),
+            manhattan,
+            |&pos| pos == end,
+        )
+        .unwrap();
+        assert_eq!(cost, 31);
+        assert_eq!(path.len(), 32);
+    }
+
+    #[test]
+    fn test_day16_sample() {
+        let graph = day16::gen(
+            "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II",
+        )
+        .unwrap();
+        let tunnels = |name: &&str| graph[*name].neighbors.iter().map(String::as_str);
+        let search = bfs(["AA"], tunnels);
+        assert_eq!(search.distance(&"HH"), Some(5));
+        assert_eq!(search.distance(&"JJ"), Some(2));
+        assert_eq!(search.distance(&"AA"), Some(0));
+    }
+}
@@ -0,0 +1,161 @@
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A day of the calendar: how to turn the puzzle input into something workable,
+/// and how to get both answers out of it.
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+
+    /// Parses `input` for the given settings. Days whose parsing depends on a
+    /// setting override this, the others just [`parse`](Solution::parse).
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    /// Applies the `key=value` settings given on the command line to the parsed
+    /// input. Days without any settings refuse them all.
+    fn configure(_input: &mut Self::Input, params: &Params) -> Result<()> {
+        params.only(&[])
+    }
+}
+
+/// Day specific settings, such as `disk_size=70000000` for day 7.
+#[derive(Clone, Debug, Default)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn parse<S: AsRef<str>>(pairs: &[S]) -> Result<Self> {
+        let mut params = BTreeMap::new();
+        for pair in pairs {
+            let pair = pair.as_ref();
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("Expected `key=value`, got `{pair}`"))?;
+            params.insert(key.trim().to_owned(), value.trim().to_owned());
+        }
+        Ok(Params(params))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Fails if there is a setting that is not in `known`.
+    pub fn only(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            None => Ok(()),
+            Some(key) if known.is_empty() => {
+                Err(anyhow!("Unknown setting `{key}`, this day has none"))
+            }
+            Some(key) => Err(anyhow!(
+                "Unknown setting `{key}`, expected one of {}",
+                known.join(", ")
+            )),
+        }
+    }
+
+    pub fn get<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.0
+            .get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .with_context(|| format!("Invalid value `{value}` for `{key}`"))
+            })
+            .transpose()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub value: String,
+    /// The answer written with `{:#}`, when that says more than `value`.
+    pub detail: Option<String>,
+    pub time: Duration,
+}
+
+impl Answer {
+    /// What the text output prints: the detail if there is one.
+    pub fn shown(&self) -> &str {
+        self.detail.as_deref().unwrap_or(&self.value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+fn wanted(part: Option<Part>, this: Part) -> bool {
+    part.is_none() || part == Some(this)
+}
+
+fn timed<T: Display>(f: impl FnOnce() -> T) -> Answer {
+    let start = Instant::now();
+    let value = f();
+    let time = start.elapsed();
+    let detail = format!("{value:#}");
+    let value = value.to_string();
+    Answer {
+        detail: (detail != value).then_some(detail),
+        value,
+        time,
+    }
+}
+
+/// Parses `input` and runs the requested parts of `S`, or both if `part` is `None`.
+pub fn run<S: Solution>(day: u8, input: &str, part: Option<Part>, params: &Params) -> Result<Run> {
+    let start = Instant::now();
+    let mut parsed = S::parse_with(input, params)?;
+    let parse_time = start.elapsed();
+    S::configure(&mut parsed, params)?;
+
+    let part1 = wanted(part, Part::One).then(|| timed(|| S::part1(&parsed)));
+    let part2 = wanted(part, Part::Two).then(|| timed(|| S::part2(&parsed)));
+
+    Ok(Run {
+        day,
+        parse_time,
+        part1,
+        part2,
+    })
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day {}", self.day)?;
+        writeln!(f, "Input parsed in: {:?}", self.parse_time)?;
+        if let Some(answer) = &self.part1 {
+            writeln!(f, "silver: {}\ntime: {:?}", answer.shown(), answer.time)?;
+        }
+        if self.part1.is_some() && self.part2.is_some() {
+            writeln!(f, "-----")?;
+        }
+        if let Some(answer) = &self.part2 {
+            writeln!(f, "gold: {}\ntime: {:?}", answer.shown(), answer.time)?;
+        }
+        Ok(())
+    }
+}
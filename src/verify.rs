@@ -0,0 +1,186 @@
+use crate::solution::{Part, Run};
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// Expected answers, read from a TOML file with one table per day:
+///
+/// ```toml
+/// [1]
+/// part1 = 70720
+/// part2 = "207148"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u8, Part), String>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read answers file {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let table: HashMap<String, HashMap<String, toml::Value>> = toml::from_str(text)?;
+        let mut answers = HashMap::new();
+        for (day, parts) in table {
+            let day: u8 = day
+                .parse()
+                .map_err(|_| anyhow!("`{day}` is not a day number"))?;
+            for (part, value) in parts {
+                let part = match part.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(anyhow!("Unknown key `{part}` for day {day}")),
+                };
+                let value = match value {
+                    toml::Value::String(s) => s,
+                    toml::Value::Integer(i) => i.to_string(),
+                    other => return Err(anyhow!("Unsupported answer {other} for day {day}")),
+                };
+                answers.insert((day, part), value);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub time: Duration,
+    pub status: Status,
+}
+
+impl Check {
+    pub fn is_fail(&self) -> bool {
+        matches!(self.status, Status::Fail { .. })
+    }
+}
+
+/// Compares every part that was run against the expected answers.
+pub fn check(run: &Run, answers: &Answers) -> Vec<Check> {
+    [(Part::One, &run.part1), (Part::Two, &run.part2)]
+        .into_iter()
+        .filter_map(|(part, answer)| {
+            let answer = answer.as_ref()?;
+            let actual = answer.value.clone();
+            let status = match answers.get(run.day, part) {
+                None => Status::Missing { actual },
+                Some(expected) if expected == actual => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.to_owned(),
+                    actual,
+                },
+            };
+            Some(Check {
+                day: run.day,
+                part,
+                time: answer.time,
+                status,
+            })
+        })
+        .collect()
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        let status = match self.status {
+            Status::Pass => "PASS",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing { .. } => "MISSING",
+        };
+        write!(
+            f,
+            "day {:>2} part {part}  {status:<7}  {:>12}",
+            self.day,
+            format!("{:.2?}", self.time)
+        )?;
+        match &self.status {
+            Status::Pass => Ok(()),
+            Status::Fail { expected, actual } => {
+                write!(f, "  expected {expected:?}, got {actual:?}")
+            }
+            Status::Missing { actual } => write!(f, "  got {actual:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::Answer;
+
+    fn answer(value: &str) -> Option<Answer> {
+        Some(Answer {
+            value: value.to_owned(),
+            detail: None,
+            time: Duration::from_millis(1),
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[5]
+part1 = \"CMZ\"
+part2 = 12
+
+[10]
+part1 = 13140",
+        )
+        .unwrap();
+        assert_eq!(answers.get(5, Part::One), Some("CMZ"));
+        assert_eq!(answers.get(5, Part::Two), Some("12"));
+        assert_eq!(answers.get(10, Part::One), Some("13140"));
+        assert_eq!(answers.get(10, Part::Two), None);
+        assert!(Answers::parse("[x]\npart1 = 1").is_err());
+        assert!(Answers::parse("[1]\npart3 = 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[4]\npart1 = 2\npart2 = 4").unwrap();
+        let run = Run {
+            day: 4,
+            parse_time: Duration::ZERO,
+            part1: answer("2"),
+            part2: answer("5"),
+        };
+        let checks = check(&run, &answers);
+        assert_eq!(checks[0].status, Status::Pass);
+        assert!(checks[1].is_fail());
+
+        let run = Run { day: 3, ..run };
+        assert!(check(&run, &answers)
+            .iter()
+            .all(|c| matches!(c.status, Status::Missing { .. })));
+    }
+
+    #[test]
+    fn test_repo_answers() {
+        let answers = Answers::load(Path::new(DEFAULT_PATH)).unwrap();
+        assert!(answers.get(10, Part::Two).unwrap().starts_with("####..##"));
+    }
+}
@@ -1,41 +1,39 @@
-use std::fs;
-use std::time::Instant;
+use crate::solution::Solution;
+use anyhow::Result;
 
-fn gen(input: &str) -> _ {
+pub struct DayN;
+
+pub fn gen(input: &str) -> _ {
 
 }
 
-fn p1() -> u32 {
+pub fn p1() -> u32 {
     0
 }
 
-fn p2() -> u32 {
+pub fn p2() -> u32 {
     0
 }
 
-fn main() {
-    let path = "inputs/8.txt";
-    let input = fs::read_to_string(path).unwrap();
-
-    let in1 = Instant::now();
-    let _ = gen(input);
-    let in0 = Instant::now();
-    println!("Input parsed in: {:?}", in0.duration_since(in1));
+impl Solution for DayN {
+    type Input = _;
+    type Output1 = u32;
+    type Output2 = u32;
 
-    let i11 = Instant::now();
-    let res1 = p1();
-    let i12 = Instant::now();
-    println!("silver: {:?}\ntime: {:?}", res1, i12.duration_since(i11));
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(gen(input))
+    }
 
-    println!("-----");
+    fn part1(input: &Self::Input) -> u32 {
+        p1()
+    }
 
-    let i21 = Instant::now();
-    let res2 = p2();
-    let i22 = Instant::now();
-    println!("gold: {:?}\ntime: {:?}", res2, i22.duration_since(i21));
+    fn part2(input: &Self::Input) -> u32 {
+        p2()
+    }
 }
 
 #[cfg(test)]
-mod d8 {
+mod dN {
     use super::*;
 }